@@ -1,7 +1,7 @@
 use collectivity::Get;
 
-fn multiply_at(
-  data: &impl for<'b> Get<usize, Value<'b> = &'b usize>,
+fn multiply_at<'a>(
+  data: &'a impl Get<usize, Value<'a> = &'a usize>,
   pos: usize,
   multiplier: usize,
 ) -> Option<usize> {
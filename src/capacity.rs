@@ -0,0 +1,343 @@
+use crate::{Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides information about the number of entries a collection can hold without reallocating.
+///
+/// Node-based collections, which allocate on every insertion, report their length. Fixed-size arrays report their size.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use collectivity::Capacity;
+///
+/// fn capacity(col: &impl Capacity) -> usize {
+///   col.capacity()
+/// }
+///
+/// assert!(capacity(&Vec::<()>::with_capacity(10)) >= 10);
+/// assert_eq!(capacity(&[0; 3]), 3);
+/// # }
+/// ```
+pub trait Capacity {
+  /// Indicates whether the `capacity` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Returns the number of entries the collection can hold without reallocating
+  fn capacity(&self) -> usize;
+}
+
+impl<C: Capacity> Capacity for &C {
+  type Safety = <C as Capacity>::Safety;
+  fn capacity(&self) -> usize {
+    <C as Capacity>::capacity(self)
+  }
+}
+
+impl<V, const N: usize> Capacity for [V; N] {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    N
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Capacity for Vec<V> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Capacity for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Capacity for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  /// Returns the length of the list
+  fn capacity(&self) -> usize {
+    self.len()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Capacity for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Capacity for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  /// Returns the length of the map
+  fn capacity(&self) -> usize {
+    self.len()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Capacity for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  /// Returns the length of the set
+  fn capacity(&self) -> usize {
+    self.len()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Capacity for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> Capacity for std::collections::HashSet<K, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Capacity for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> Capacity for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Capacity for slab::Slab<V> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Capacity for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Capacity for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Capacity for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    self.capacity()
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Capacity for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    hashbrown::HashMap::capacity(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Capacity for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    hashbrown::HashSet::capacity(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Capacity for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    arrayvec::ArrayVec::capacity(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Capacity for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    heapless::Vec::capacity(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Capacity for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    heapless::Deque::capacity(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Capacity
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    heapless::BinaryHeap::capacity(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Capacity for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    heapless::IndexMap::capacity(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Capacity for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    tinyvec::ArrayVec::capacity(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Capacity for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn capacity(&self) -> usize {
+    tinyvec::TinyVec::capacity(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn vec() {
+    assert!(<Vec<()> as Capacity>::capacity(&Vec::with_capacity(4)) >= 4);
+  }
+  #[test]
+  fn linked_list() {
+    assert_eq!(
+      <alloc::collections::LinkedList<i32> as Capacity>::capacity(&[0, 1].into()),
+      2
+    );
+  }
+  #[test]
+  fn hash_map() {
+    assert!(
+      <std::collections::HashMap<i32, i32> as Capacity>::capacity(
+        &std::collections::HashMap::with_capacity(4)
+      ) >= 4
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 4]> as Capacity>::capacity(&smallvec::SmallVec::new()),
+      4
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let m = indexmap::IndexMap::<i32, i32>::with_capacity(4);
+    assert!(<indexmap::IndexMap<i32, i32> as Capacity>::capacity(&m) >= 4);
+    let s = indexmap::IndexSet::<i32>::with_capacity(4);
+    assert!(<indexmap::IndexSet<i32> as Capacity>::capacity(&s) >= 4);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let m = hashbrown::HashMap::<i32, i32>::with_capacity(4);
+    assert!(<hashbrown::HashMap<i32, i32> as Capacity>::capacity(&m) >= 4);
+    let s = hashbrown::HashSet::<i32>::with_capacity(4);
+    assert!(<hashbrown::HashSet<i32> as Capacity>::capacity(&s) >= 4);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    assert_eq!(
+      <arrayvec::ArrayVec<i32, 4> as Capacity>::capacity(&arrayvec::ArrayVec::new()),
+      4
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    assert_eq!(
+      <heapless::Vec<i32, 4> as Capacity>::capacity(&heapless::Vec::new()),
+      4
+    );
+    assert_eq!(
+      <heapless::Deque<i32, 4> as Capacity>::capacity(&heapless::Deque::new()),
+      4
+    );
+    assert_eq!(
+      <heapless::FnvIndexMap<i32, i32, 4> as Capacity>::capacity(&heapless::FnvIndexMap::new()),
+      4
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    assert_eq!(
+      <tinyvec::ArrayVec<[i32; 4]> as Capacity>::capacity(&tinyvec::ArrayVec::new()),
+      4
+    );
+  }
+}
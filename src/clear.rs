@@ -0,0 +1,383 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Provides the ability to remove all entries from a collection.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::{Clear, Len};
+///
+/// fn reset(col: &mut (impl Clear + Len)) -> usize {
+///   col.clear();
+///   col.len()
+/// }
+///
+/// assert_eq!(reset(&mut vec![1, 2, 3]), 0);
+/// assert_eq!(reset(&mut HashMap::from([("A", 1)])), 0);
+/// # }
+/// ```
+pub trait Clear {
+  /// Indicates whether the `clear` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes all entries from the collection.
+  fn clear(&mut self);
+}
+
+impl<C: Clear> Clear for &mut C {
+  type Safety = <C as Clear>::Safety;
+  fn clear(&mut self) {
+    <C as Clear>::clear(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Clear for Vec<V> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Clear for VecDeque<V> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Clear for LinkedList<V> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Clear for BinaryHeap<V> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Clear for BTreeMap<K, V> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Clear for BTreeSet<K> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Clear for HashMap<K, V, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> Clear for HashSet<K, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "dashmap")]
+use dashmap::{DashMap, DashSet};
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Clear for DashMap<K, V, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    DashMap::clear(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> Clear for DashSet<K, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    DashSet::clear(self)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl Clear for SeV {
+  type Safety = Safe;
+  /// Removes all items of arrays and all entries of objects. Other values are left unchanged.
+  fn clear(&mut self) {
+    match self {
+      SeV::Array(a) => a.clear(),
+      SeV::Object(o) => o.clear(),
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl Clear for SBV<'_> {
+  type Safety = Safe;
+  /// Removes all items of arrays and all entries of objects. Other values are left unchanged.
+  fn clear(&mut self) {
+    match self {
+      SBV::Array(a) => a.clear(),
+      SBV::Object(o) => o.clear(),
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Clear for SOV {
+  type Safety = Safe;
+  /// Removes all items of arrays and all entries of objects. Other values are left unchanged.
+  fn clear(&mut self) {
+    match self {
+      SOV::Array(a) => a.clear(),
+      SOV::Object(o) => o.clear(),
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Clear for slab::Slab<V> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Clear for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Clear for IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Clear for IndexSet<K, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    self.clear()
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Clear for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    hashbrown::HashMap::clear(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Clear for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    hashbrown::HashSet::clear(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Clear for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    arrayvec::ArrayVec::clear(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Clear for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    heapless::Vec::clear(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Clear for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    heapless::Deque::clear(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Clear
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  fn clear(&mut self) {
+    heapless::BinaryHeap::clear(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Clear for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    heapless::IndexMap::clear(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Clear for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    tinyvec::ArrayVec::clear(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Clear for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn clear(&mut self) {
+    tinyvec::TinyVec::clear(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = vec![1];
+    <Vec<i32> as Clear>::clear(&mut v);
+    assert!(v.is_empty());
+    let mut v = VecDeque::from([1]);
+    <VecDeque<i32> as Clear>::clear(&mut v);
+    assert!(v.is_empty());
+    let mut l = LinkedList::from([1]);
+    <LinkedList<i32> as Clear>::clear(&mut l);
+    assert!(l.is_empty());
+    let mut h = BinaryHeap::from([1]);
+    <BinaryHeap<i32> as Clear>::clear(&mut h);
+    assert!(h.is_empty());
+    let mut m = BTreeMap::from([(0, 1)]);
+    <BTreeMap<i32, i32> as Clear>::clear(&mut m);
+    assert!(m.is_empty());
+    let mut s = BTreeSet::from([0]);
+    <BTreeSet<i32> as Clear>::clear(&mut s);
+    assert!(s.is_empty());
+    let mut m = HashMap::from([(0, 1)]);
+    <HashMap<i32, i32> as Clear>::clear(&mut m);
+    assert!(m.is_empty());
+    let mut s = HashSet::from([0]);
+    <HashSet<i32> as Clear>::clear(&mut s);
+    assert!(s.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dashmap() {
+    let mut m = DashMap::new();
+    m.insert(0, 1);
+    <DashMap<i32, i32> as Clear>::clear(&mut m);
+    assert!(m.is_empty());
+    let mut s = DashSet::new();
+    s.insert(0);
+    <DashSet<i32> as Clear>::clear(&mut s);
+    assert!(s.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut a = serde_json::json!([0]);
+    Clear::clear(&mut a);
+    assert_eq!(a, serde_json::json!([]));
+    let mut o = serde_json::json!({ "a": 0 });
+    Clear::clear(&mut o);
+    assert_eq!(o, serde_json::json!({}));
+    let mut n = SeV::Null;
+    Clear::clear(&mut n);
+    assert_eq!(n, SeV::Null);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::from([(0, 1)]);
+    <IndexMap<i32, i32> as Clear>::clear(&mut m);
+    assert!(m.is_empty());
+    let mut s = IndexSet::from([0]);
+    <IndexSet<i32> as Clear>::clear(&mut s);
+    assert!(s.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(0, 1)]);
+    <hashbrown::HashMap<i32, i32> as Clear>::clear(&mut m);
+    assert!(m.is_empty());
+    let mut s = hashbrown::HashSet::from([0]);
+    <hashbrown::HashSet<i32> as Clear>::clear(&mut s);
+    assert!(s.is_empty());
+  }
+}
@@ -0,0 +1,440 @@
+#[cfg(feature = "heapless")]
+use crate::ExtendPush;
+#[cfg(feature = "alloc")]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Unsafe;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Provides the ability to construct a collection from an iterator in a single step.
+///
+/// Sequences are built from values, maps from key-value pairs and sets from keys, mirroring the items accepted by `ExtendPush` and `ExtendInsert`.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{BTreeMap, VecDeque};
+/// use collectivity::{CollectFrom, Len};
+///
+/// fn build<C: CollectFrom<(usize, usize)> + Len>(n: usize) -> C {
+///   C::collect_from((0..n).map(|n| (n, n)))
+/// }
+///
+/// assert_eq!(build::<BTreeMap<_, _>>(3).len(), 3);
+/// assert_eq!(build::<Vec<_>>(3).len(), 3);
+/// assert_eq!(VecDeque::collect_from([0, 1]), VecDeque::from([0, 1]));
+/// # }
+/// ```
+pub trait CollectFrom<V>: Sized {
+  /// Indicates whether the `collect_from` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Creates a collection holding all items yielded by `iter`.
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self;
+}
+
+#[cfg(feature = "alloc")]
+impl<V> CollectFrom<V> for Vec<V> {
+  type Safety = Unsafe;
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> CollectFrom<V> for VecDeque<V> {
+  type Safety = Unsafe;
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> CollectFrom<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> CollectFrom<V> for BinaryHeap<V> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> CollectFrom<(K, V)> for BTreeMap<K, V> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> CollectFrom<K> for BTreeSet<K> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = K>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher + Default> CollectFrom<(K, V)> for HashMap<K, V, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher + Default> CollectFrom<K> for HashSet<K, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = K>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "dashmap")]
+use dashmap::{DashMap, DashSet};
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone + Default> CollectFrom<(K, V)> for DashMap<K, V, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    let mut c = Self::default();
+    c.extend(iter);
+    c
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone + Default> CollectFrom<K> for DashSet<K, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = K>>(iter: I) -> Self {
+    let mut c = Self::default();
+    c.extend(iter);
+    c
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl CollectFrom<SeV> for SeV {
+  type Safety = Safe;
+  /// Creates an array
+  fn collect_from<I: IntoIterator<Item = SeV>>(iter: I) -> Self {
+    SeV::Array(iter.into_iter().collect())
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl CollectFrom<(String, SeV)> for SeV {
+  type Safety = Safe;
+  /// Creates an object
+  fn collect_from<I: IntoIterator<Item = (String, SeV)>>(iter: I) -> Self {
+    SeV::Object(iter.into_iter().collect())
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{cow::Cow, BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a> CollectFrom<SBV<'a>> for SBV<'a> {
+  type Safety = Safe;
+  /// Creates an array
+  fn collect_from<I: IntoIterator<Item = SBV<'a>>>(iter: I) -> Self {
+    SBV::Array(iter.into_iter().collect())
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'a> CollectFrom<(Cow<'a, str>, SBV<'a>)> for SBV<'a> {
+  type Safety = Safe;
+  /// Creates an object
+  fn collect_from<I: IntoIterator<Item = (Cow<'a, str>, SBV<'a>)>>(iter: I) -> Self {
+    SBV::Object(Box::new(iter.into_iter().collect()))
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl CollectFrom<SOV> for SOV {
+  type Safety = Safe;
+  /// Creates an array
+  fn collect_from<I: IntoIterator<Item = SOV>>(iter: I) -> Self {
+    SOV::Array(iter.into_iter().collect())
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl CollectFrom<(String, SOV)> for SOV {
+  type Safety = Safe;
+  /// Creates an object
+  fn collect_from<I: IntoIterator<Item = (String, SOV)>>(iter: I) -> Self {
+    SOV::Object(Box::new(iter.into_iter().collect()))
+  }
+}
+
+#[cfg(feature = "slab")]
+use slab::Slab;
+
+#[cfg(feature = "slab")]
+impl<V> CollectFrom<V> for Slab<V> {
+  type Safety = Unsafe;
+  /// Assigns consecutive keys starting at 0
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    let iter = iter.into_iter();
+    let mut s = Slab::with_capacity(iter.size_hint().0);
+    iter.for_each(|v| {
+      s.insert(v);
+    });
+    s
+  }
+}
+
+#[cfg(feature = "smallvec")]
+use smallvec::{Array, SmallVec};
+
+#[cfg(feature = "smallvec")]
+impl<V, A: Array<Item = V>> CollectFrom<V> for SmallVec<A> {
+  type Safety = Unsafe;
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Default> CollectFrom<(K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher + Default> CollectFrom<K> for IndexSet<K, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = K>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher + Default> CollectFrom<(K, V)>
+  for hashbrown::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher + Default> CollectFrom<K> for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn collect_from<I: IntoIterator<Item = K>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> CollectFrom<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the capacity
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> CollectFrom<V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the capacity
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> CollectFrom<V> for heapless::Deque<V, N> {
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the capacity
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    let mut d = Self::new();
+    d.extend_push(&mut iter.into_iter());
+    d
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> CollectFrom<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the capacity
+  fn collect_from<I: IntoIterator<Item = V>>(iter: I) -> Self {
+    let mut h = Self::new();
+    h.extend_push(&mut iter.into_iter());
+    h
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher + Default, const N: usize> CollectFrom<(K, V)>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more keys than the capacity
+  fn collect_from<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> CollectFrom<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the capacity
+  fn collect_from<I: IntoIterator<Item = A::Item>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> CollectFrom<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  fn collect_from<I: IntoIterator<Item = A::Item>>(iter: I) -> Self {
+    iter.into_iter().collect()
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!(Vec::collect_from(0..2), vec![0, 1]);
+    assert_eq!(VecDeque::collect_from(0..2), VecDeque::from([0, 1]));
+    assert_eq!(LinkedList::collect_from(0..2), LinkedList::from([0, 1]));
+    assert_eq!(BinaryHeap::collect_from(0..2).peek(), Some(&1));
+    assert_eq!(BTreeMap::collect_from([(0, 1)]), BTreeMap::from([(0, 1)]));
+    assert_eq!(BTreeSet::collect_from([0]), BTreeSet::from([0]));
+    assert_eq!(HashMap::collect_from([(0, 1)]), HashMap::from([(0, 1)]));
+    assert_eq!(HashSet::collect_from([0]), HashSet::from([0]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    assert_eq!(SeV::collect_from([SeV::Null]), serde_json::json!([null]));
+    assert_eq!(
+      SeV::collect_from([("a".to_string(), SeV::Null)]),
+      serde_json::json!({ "a": null })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let s = Slab::collect_from([5, 6]);
+    assert_eq!(s[1], 6);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    assert_eq!(
+      IndexMap::<_, _>::collect_from([(1, 0), (0, 1)])
+        .keys()
+        .collect::<Vec<_>>(),
+      [&1, &0]
+    );
+    assert_eq!(
+      IndexSet::<_>::collect_from([1, 0])
+        .iter()
+        .collect::<Vec<_>>(),
+      [&1, &0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    assert_eq!(
+      hashbrown::HashMap::<_, _>::collect_from([(0, 1)]),
+      hashbrown::HashMap::from([(0, 1)])
+    );
+    assert_eq!(
+      hashbrown::HashSet::<_>::collect_from([0]),
+      hashbrown::HashSet::from([0])
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let v = arrayvec::ArrayVec::<i32, 4>::collect_from([1, 2]);
+    assert_eq!(v.as_slice(), &[1, 2]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let d = heapless::Deque::<i32, 4>::collect_from([1, 2]);
+    assert_eq!(d.iter().collect::<Vec<_>>(), [&1, &2]);
+    let h = heapless::BinaryHeap::<i32, heapless::binary_heap::Max, 4>::collect_from([1, 3, 2]);
+    assert_eq!(h.peek(), Some(&3));
+    let m = heapless::FnvIndexMap::<i32, i32, 4>::collect_from([(1, 2)]);
+    assert_eq!(m.get(&1), Some(&2));
+  }
+  #[test]
+  #[should_panic]
+  fn heapless_full() {
+    heapless::Deque::<i32, 1>::collect_from([1, 2]);
+  }
+}
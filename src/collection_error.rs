@@ -0,0 +1,145 @@
+/// Error returned by the fallible collection traits
+///
+/// ## Examples
+/// ```
+/// use collectivity::{CollectionError, TryInsert};
+///
+/// let mut v = vec![0];
+/// assert_eq!(
+///   v.try_insert(5, 1),
+///   Err(CollectionError::OutOfBounds { index: 5, len: 1 })
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CollectionError {
+  /// Indicates the index lies beyond the end of the collection.
+  OutOfBounds {
+    /// The requested index
+    index: usize,
+    /// The length of the collection at the time of the request
+    len: usize,
+  },
+  /// Indicates a JSON value is not of the kind required by the operation, e.g. an index-based insert into an object.
+  WrongValueKind {
+    /// The kind required by the operation
+    expected: &'static str,
+    /// The actual kind of the value
+    found: &'static str,
+  },
+  /// Indicates the requested capacity exceeds the collection's maximum.
+  CapacityExceeded,
+  /// Indicates the allocator failed to provide the requested memory.
+  AllocationFailed,
+  /// Indicates the key is missing from the collection.
+  KeyNotFound,
+}
+
+impl core::fmt::Display for CollectionError {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self {
+      CollectionError::OutOfBounds { index, len } => {
+        write!(f, "The index {index} is out of bounds for length {len}")
+      }
+      CollectionError::WrongValueKind { expected, found } => {
+        write!(f, "Expected a JSON {expected}, found {found}")
+      }
+      CollectionError::CapacityExceeded => write!(f, "The requested capacity exceeds the maximum"),
+      CollectionError::AllocationFailed => write!(f, "The memory allocator returned an error"),
+      CollectionError::KeyNotFound => write!(f, "The key is missing from the collection"),
+    }
+  }
+}
+
+impl core::error::Error for CollectionError {}
+
+/// Names the kind of a JSON value for `CollectionError::WrongValueKind`.
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+pub(crate) trait ValueKind {
+  fn kind(&self) -> &'static str;
+}
+
+#[cfg(feature = "serde_json")]
+impl ValueKind for serde_json::Value {
+  fn kind(&self) -> &'static str {
+    match self {
+      serde_json::Value::Null => "null",
+      serde_json::Value::Bool(_) => "boolean",
+      serde_json::Value::Number(_) => "number",
+      serde_json::Value::String(_) => "string",
+      serde_json::Value::Array(_) => "array",
+      serde_json::Value::Object(_) => "object",
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+fn static_kind(n: &simd_json::StaticNode) -> &'static str {
+  match n {
+    simd_json::StaticNode::Null => "null",
+    simd_json::StaticNode::Bool(_) => "boolean",
+    _ => "number",
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl ValueKind for simd_json::BorrowedValue<'_> {
+  fn kind(&self) -> &'static str {
+    match self {
+      simd_json::BorrowedValue::Static(n) => static_kind(n),
+      simd_json::BorrowedValue::String(_) => "string",
+      simd_json::BorrowedValue::Array(_) => "array",
+      simd_json::BorrowedValue::Object(_) => "object",
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl ValueKind for simd_json::OwnedValue {
+  fn kind(&self) -> &'static str {
+    match self {
+      simd_json::OwnedValue::Static(n) => static_kind(n),
+      simd_json::OwnedValue::String(_) => "string",
+      simd_json::OwnedValue::Array(_) => "array",
+      simd_json::OwnedValue::Object(_) => "object",
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  use std::string::ToString;
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      CollectionError::OutOfBounds { index: 3, len: 2 }.to_string(),
+      "The index 3 is out of bounds for length 2"
+    );
+    assert_eq!(
+      CollectionError::WrongValueKind {
+        expected: "array",
+        found: "object"
+      }
+      .to_string(),
+      "Expected a JSON array, found object"
+    );
+    assert_eq!(
+      CollectionError::KeyNotFound.to_string(),
+      "The key is missing from the collection"
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+
+  #[test]
+  fn serde_json() {
+    assert_eq!(serde_json::json!({}).kind(), "object");
+    assert_eq!(serde_json::json!(1.5).kind(), "number");
+  }
+}
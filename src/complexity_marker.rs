@@ -0,0 +1,29 @@
+/// Groups types used to indicate the time complexity of a trait implementation with respect to the collection size
+pub trait ComplexityMarker {}
+
+/// Groups complexity markers that grow slower than the collection size
+pub trait SubLinear: ComplexityMarker {}
+
+/// Groups complexity markers that don't grow with the collection size, at least on average
+pub trait AmortizedConstantOrBetter: SubLinear {}
+
+/// Indicates a trait implementation runs in constant time
+pub struct Constant {}
+impl ComplexityMarker for Constant {}
+impl SubLinear for Constant {}
+impl AmortizedConstantOrBetter for Constant {}
+
+/// Indicates a trait implementation runs in constant time on average, with occasional slower calls, e.g. when reallocating
+pub struct AmortizedConstant {}
+impl ComplexityMarker for AmortizedConstant {}
+impl SubLinear for AmortizedConstant {}
+impl AmortizedConstantOrBetter for AmortizedConstant {}
+
+/// Indicates a trait implementation runs in logarithmic time
+pub struct Logarithmic {}
+impl ComplexityMarker for Logarithmic {}
+impl SubLinear for Logarithmic {}
+
+/// Indicates a trait implementation runs in linear time
+pub struct Linear {}
+impl ComplexityMarker for Linear {}
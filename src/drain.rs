@@ -0,0 +1,599 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to remove all entries from a collection, returning them as an iterator.
+///
+/// Maps yield key-value pairs, while other collections yield their values. JSON values yield the items of arrays and the values of objects. The collection is empty once `drain` returns, even if the iterator is not fully consumed. To drain a part of a sequence, see [`DrainRange`](crate::DrainRange).
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::VecDeque;
+/// use collectivity::Drain;
+///
+/// fn take_all<'a, C: Drain<Drain<'a> = impl Iterator<Item = i32>>>(col: &'a mut C) -> Vec<i32> {
+///   col.drain().collect()
+/// }
+///
+/// let mut v = VecDeque::from([1, 2, 3]);
+/// assert_eq!(take_all(&mut v), vec![1, 2, 3]);
+/// assert!(v.is_empty());
+/// # }
+/// ```
+pub trait Drain {
+  /// Indicates whether the `drain` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `drain`.
+  type Drain<'a>: Iterator
+  where
+    Self: 'a;
+  /// Removes all entries from the collection and returns them as an iterator.
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a>;
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Drain for Vec<V> {
+  type Safety = Safe;
+  type Drain<'a>
+    = alloc::vec::Drain<'a, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    Vec::drain(self, ..)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Drain for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type Drain<'a>
+    = alloc::collections::vec_deque::Drain<'a, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    alloc::collections::VecDeque::drain(self, ..)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Drain for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type Drain<'a>
+    = alloc::collections::linked_list::IntoIter<V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Drain for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  type Drain<'a>
+    = alloc::collections::binary_heap::Drain<'a, V>
+  where
+    Self: 'a;
+
+  /// Yields values in arbitrary order
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    alloc::collections::BinaryHeap::drain(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Drain for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Drain<'a>
+    = alloc::collections::btree_map::IntoIter<K, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Drain for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type Drain<'a>
+    = alloc::collections::btree_set::IntoIter<K>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Drain for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = std::collections::hash_map::Drain<'a, K, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    std::collections::HashMap::drain(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> Drain for std::collections::HashSet<K, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = std::collections::hash_set::Drain<'a, K>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    std::collections::HashSet::drain(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone + Default> Drain for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = dashmap::iter::OwningIter<K, V, S>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone + Default> Drain for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = dashmap::iter_set::OwningIter<K, S>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl Drain for serde_json::Value {
+  type Safety = Safe;
+  type Drain<'a>
+    = crate::JsonIter<
+    alloc::vec::Drain<'a, serde_json::Value>,
+    core::iter::Map<
+      serde_json::map::IntoIter,
+      fn((String, serde_json::Value)) -> serde_json::Value,
+    >,
+  >
+  where
+    Self: 'a;
+
+  /// Drains the items of arrays and the values of objects. Other values are left unchanged and yield nothing.
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    match self {
+      serde_json::Value::Array(a) => crate::JsonIter::Array(a.drain(..)),
+      serde_json::Value::Object(o) => {
+        crate::JsonIter::Object(core::mem::take(o).into_iter().map(|(_, v)| v))
+      }
+      _ => crate::JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> Drain for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type Drain<'a>
+    = crate::JsonIter<
+    alloc::vec::Drain<'a, simd_json::BorrowedValue<'v>>,
+    core::iter::Map<
+      halfbrown::Drain<'a, simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>>,
+      fn(
+        (simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>),
+      ) -> simd_json::BorrowedValue<'v>,
+    >,
+  >
+  where
+    Self: 'a;
+
+  /// Drains the items of arrays and the values of objects. Other values are left unchanged and yield nothing.
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    match self {
+      simd_json::BorrowedValue::Array(a) => crate::JsonIter::Array(a.drain(..)),
+      simd_json::BorrowedValue::Object(o) => crate::JsonIter::Object(o.drain().map(|(_, v)| v)),
+      _ => crate::JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Drain for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Drain<'a>
+    = crate::JsonIter<
+    alloc::vec::Drain<'a, simd_json::OwnedValue>,
+    core::iter::Map<
+      halfbrown::Drain<'a, String, simd_json::OwnedValue>,
+      fn((String, simd_json::OwnedValue)) -> simd_json::OwnedValue,
+    >,
+  >
+  where
+    Self: 'a;
+
+  /// Drains the items of arrays and the values of objects. Other values are left unchanged and yield nothing.
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    match self {
+      simd_json::OwnedValue::Array(a) => crate::JsonIter::Array(a.drain(..)),
+      simd_json::OwnedValue::Object(o) => crate::JsonIter::Object(o.drain().map(|(_, v)| v)),
+      _ => crate::JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Drain for slab::Slab<V> {
+  type Safety = Safe;
+  type Drain<'a>
+    = slab::Drain<'a, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    slab::Slab::drain(self)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Drain for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type Drain<'a>
+    = smallvec::Drain<'a, A>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    smallvec::SmallVec::drain(self, ..)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Drain for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = indexmap::map::Drain<'a, K, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    indexmap::IndexMap::drain(self, ..)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Drain for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = indexmap::set::Drain<'a, K>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    indexmap::IndexSet::drain(self, ..)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Drain for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = hashbrown::hash_map::Drain<'a, K, V>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    hashbrown::HashMap::drain(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Drain for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  type Drain<'a>
+    = hashbrown::hash_set::Drain<'a, K>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    hashbrown::HashSet::drain(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Drain for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Drain<'a>
+    = arrayvec::Drain<'a, V, CAP>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    arrayvec::ArrayVec::drain(self, ..)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Drain for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Drain<'a>
+    = <heapless::Vec<V, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Drain for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Drain<'a>
+    = <heapless::Deque<V, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Drain
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  type Drain<'a>
+    = <heapless::Vec<V, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  /// Yields the values in arbitrary order
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_vec().into_iter()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher + Default, const N: usize> Drain
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  type Drain<'a>
+    = <heapless::IndexMap<K, V, S, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    core::mem::take(self).into_iter()
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Drain for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Drain<'a>
+    = tinyvec::ArrayVecDrain<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    tinyvec::ArrayVec::drain(self, ..)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Drain for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Drain<'a>
+    = tinyvec::TinyVecDrain<'a, A>
+  where
+    Self: 'a;
+
+  fn drain<'a>(&'a mut self) -> Self::Drain<'a> {
+    tinyvec::TinyVec::drain(self, ..)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn vec() {
+    let mut v = vec![1, 2];
+    assert_eq!(
+      <Vec<i32> as Drain>::drain(&mut v).collect::<Vec<_>>(),
+      vec![1, 2]
+    );
+    assert!(v.is_empty());
+  }
+  #[test]
+  fn linked_list() {
+    let mut l = alloc::collections::LinkedList::from([1, 2]);
+    assert_eq!(
+      <alloc::collections::LinkedList<i32> as Drain>::drain(&mut l).collect::<Vec<_>>(),
+      vec![1, 2]
+    );
+    assert!(l.is_empty());
+  }
+  #[test]
+  fn b_tree_map() {
+    let mut m = alloc::collections::BTreeMap::from([(0, 1), (1, 2)]);
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, i32> as Drain>::drain(&mut m).collect::<Vec<_>>(),
+      vec![(0, 1), (1, 2)]
+    );
+    assert!(m.is_empty());
+  }
+  #[test]
+  fn hash_set() {
+    let mut s = std::collections::HashSet::from([0]);
+    assert_eq!(
+      <std::collections::HashSet<i32> as Drain>::drain(&mut s).collect::<Vec<_>>(),
+      vec![0]
+    );
+    assert!(s.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dash_map() {
+    let mut m = dashmap::DashMap::new();
+    m.insert(0, 1);
+    assert_eq!(
+      <dashmap::DashMap<i32, i32> as Drain>::drain(&mut m).collect::<Vec<_>>(),
+      vec![(0, 1)]
+    );
+    assert!(m.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut o = serde_json::json!({ "a": 0 });
+    assert_eq!(
+      Drain::drain(&mut o).collect::<Vec<_>>(),
+      vec![serde_json::json!(0)]
+    );
+    assert_eq!(o, serde_json::json!({}));
+    assert_eq!(Drain::drain(&mut serde_json::Value::Null).next(), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  #[test]
+  fn simd_json_owned() {
+    let mut a = simd_json::OwnedValue::Array(vec![simd_json::OwnedValue::Static(
+      simd_json::StaticNode::Null,
+    )]);
+    assert_eq!(Drain::drain(&mut a).count(), 1);
+    assert_eq!(a, simd_json::OwnedValue::Array(vec![]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn index_map() {
+    let mut m = indexmap::IndexMap::from([(1, 2), (0, 1)]);
+    assert_eq!(
+      <indexmap::IndexMap<i32, i32> as Drain>::drain(&mut m).collect::<Vec<_>>(),
+      vec![(1, 2), (0, 1)]
+    );
+    assert!(m.is_empty());
+  }
+  #[test]
+  fn index_set() {
+    let mut s = indexmap::IndexSet::from([1, 0]);
+    assert_eq!(
+      <indexmap::IndexSet<i32> as Drain>::drain(&mut s).collect::<Vec<_>>(),
+      vec![1, 0]
+    );
+    assert!(s.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(0, 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as Drain>::drain(&mut m).collect::<Vec<_>>(),
+      [(0, 1)]
+    );
+    assert!(m.is_empty());
+    let mut s = hashbrown::HashSet::from([0]);
+    assert_eq!(
+      <hashbrown::HashSet<i32> as Drain>::drain(&mut s).collect::<Vec<_>>(),
+      [0]
+    );
+    assert!(s.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 4>::from_iter([1, 2]);
+    assert_eq!(Drain::drain(&mut v).collect::<Vec<_>>(), vec![1, 2]);
+    assert!(v.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut v = heapless::Vec::<i32, 4>::from_slice(&[1, 2]).unwrap();
+    assert_eq!(Drain::drain(&mut v).collect::<Vec<_>>(), vec![1, 2]);
+    assert!(v.is_empty());
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Min, 4>::new();
+    h.push(2).unwrap();
+    h.push(1).unwrap();
+    let mut drained = Drain::drain(&mut h).collect::<Vec<_>>();
+    drained.sort();
+    assert_eq!(drained, vec![1, 2]);
+    assert!(h.is_empty());
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 4]>::from_iter([1, 2]);
+    assert_eq!(Drain::drain(&mut v).collect::<Vec<_>>(), vec![1, 2]);
+    assert!(v.is_empty());
+  }
+}
@@ -0,0 +1,144 @@
+use crate::SafetyMarker;
+#[cfg(any(feature = "alloc", feature = "arrayvec", feature = "tinyvec"))]
+use crate::Unsafe;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::RangeBounds;
+
+/// Provides the ability to remove a range of values from a sequence, returning them as an iterator.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use collectivity::DrainRange;
+///
+/// fn take_head<'a, C: DrainRange<Drain<'a> = impl Iterator<Item = i32>>>(col: &'a mut C) -> Vec<i32> {
+///   col.drain_range(..2).collect()
+/// }
+///
+/// let mut v = vec![1, 2, 3];
+/// assert_eq!(take_head(&mut v), vec![1, 2]);
+/// assert_eq!(v, vec![3]);
+/// # }
+/// ```
+pub trait DrainRange {
+  /// Indicates whether the `drain_range` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `drain_range`.
+  type Drain<'a>: Iterator
+  where
+    Self: 'a;
+  /// Removes the values in `range` from the sequence and returns them as an iterator.
+  ///
+  /// Panics if the range is out of bounds.
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a>;
+}
+
+#[cfg(feature = "alloc")]
+impl<V> DrainRange for Vec<V> {
+  type Safety = Unsafe;
+  type Drain<'a>
+    = alloc::vec::Drain<'a, V>
+  where
+    Self: 'a;
+
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a> {
+    Vec::drain(self, range)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> DrainRange for alloc::collections::VecDeque<V> {
+  type Safety = Unsafe;
+  type Drain<'a>
+    = alloc::collections::vec_deque::Drain<'a, V>
+  where
+    Self: 'a;
+
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a> {
+    alloc::collections::VecDeque::drain(self, range)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> DrainRange for smallvec::SmallVec<A> {
+  type Safety = Unsafe;
+  type Drain<'a>
+    = smallvec::Drain<'a, A>
+  where
+    Self: 'a;
+
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a> {
+    smallvec::SmallVec::drain(self, range)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> DrainRange for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  type Drain<'a>
+    = arrayvec::Drain<'a, V, CAP>
+  where
+    Self: 'a;
+
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a> {
+    arrayvec::ArrayVec::drain(self, range)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> DrainRange for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  type Drain<'a>
+    = tinyvec::ArrayVecDrain<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a> {
+    tinyvec::ArrayVec::drain(self, range)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> DrainRange for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  type Drain<'a>
+    = tinyvec::TinyVecDrain<'a, A>
+  where
+    Self: 'a;
+
+  fn drain_range<'a, R: RangeBounds<usize>>(&'a mut self, range: R) -> Self::Drain<'a> {
+    tinyvec::TinyVec::drain(self, range)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn vec_deque() {
+    let mut v = alloc::collections::VecDeque::from([1, 2, 3]);
+    assert_eq!(
+      <alloc::collections::VecDeque<i32> as DrainRange>::drain_range(&mut v, 1..)
+        .collect::<Vec<_>>(),
+      vec![2, 3]
+    );
+    assert_eq!(v, alloc::collections::VecDeque::from([1]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = smallvec::SmallVec::<[i32; 2]>::from_slice(&[1, 2, 3]);
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 2]> as DrainRange>::drain_range(&mut sv, ..1).collect::<Vec<_>>(),
+      vec![1]
+    );
+    assert_eq!(sv.as_slice(), &[2, 3]);
+  }
+}
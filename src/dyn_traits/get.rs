@@ -0,0 +1,47 @@
+use crate::Get;
+
+/// Object-safe `Get` for implementations returning plain references.
+///
+/// Implemented for every `'static` implementer of `Get` whose `Value<'a>` is `&'a V`, so heterogeneous collections can be stored behind `dyn DynGet<K, V>`.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::dyn_traits::DynGet;
+///
+/// let cols: Vec<Box<dyn DynGet<&str, i32>>> = vec![
+///   Box::new(HashMap::from([("A", 1)])),
+///   Box::new(std::collections::BTreeMap::from([("A", 2)])),
+/// ];
+/// assert_eq!(cols.iter().map(|c| c.get("A")).collect::<Vec<_>>(), [Some(&1), Some(&2)]);
+/// # }
+/// ```
+pub trait DynGet<K, V> {
+  /// `get` returning a reference
+  fn get(&self, k: K) -> Option<&V>;
+  /// `contains` of the underlying implementation
+  fn contains(&self, k: K) -> bool;
+}
+
+impl<K, V, G: ?Sized + 'static + for<'a> Get<K, Value<'a> = &'a V>> DynGet<K, V> for G {
+  fn get(&self, k: K) -> Option<&V> {
+    G::get(self, k)
+  }
+  fn contains(&self, k: K) -> bool {
+    G::contains(self, k)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn boxed() {
+    let cols: Vec<Box<dyn DynGet<usize, i32>>> = vec![Box::new(vec![1, 2]), Box::new([1, 2])];
+    assert!(cols.iter().all(|c| c.get(1) == Some(&2)));
+    assert!(cols.iter().all(|c| !c.contains(2)));
+  }
+}
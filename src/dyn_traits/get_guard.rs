@@ -0,0 +1,53 @@
+use crate::Get;
+use alloc::boxed::Box;
+use core::ops::Deref;
+
+/// Object-safe `Get` for implementations returning guards, e.g. `DashMap`.
+///
+/// Implemented for every `'static` implementer of `Get` whose `Value<'a>` dereferences to `V`, plain references included. The returned value is boxed, which requires `K` to be `'static` as well.
+pub trait DynGetGuard<K, V> {
+  /// `get` returning a boxed guard
+  fn get_guard(&self, k: K) -> Option<Box<dyn Deref<Target = V> + '_>>;
+}
+
+impl<K: 'static, V, G> DynGetGuard<K, V> for G
+where
+  G: ?Sized + 'static + Get<K>,
+  for<'a> G::Value<'a>: Deref<Target = V>,
+{
+  fn get_guard(&self, k: K) -> Option<Box<dyn Deref<Target = V> + '_>> {
+    G::get(self, k).map(|v| Box::new(v) as Box<dyn Deref<Target = V> + '_>)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn references() {
+    let col: Box<dyn DynGetGuard<usize, i32>> = Box::new(vec![1, 2]);
+    assert_eq!(col.get_guard(1).map(|v| **v), Some(2));
+    assert!(col.get_guard(2).is_none());
+  }
+
+  #[cfg(feature = "dashmap")]
+  #[test]
+  fn dashmap() {
+    use dashmap::DashMap;
+    let m = DashMap::new();
+    m.insert("A", 1);
+    let cols: Vec<Box<dyn DynGetGuard<&str, i32>>> = vec![
+      Box::new(m),
+      Box::new(std::collections::HashMap::from([("A", 2)])),
+    ];
+    assert_eq!(
+      cols
+        .iter()
+        .map(|c| **c.get_guard("A").unwrap())
+        .collect::<Vec<_>>(),
+      [1, 2]
+    );
+  }
+}
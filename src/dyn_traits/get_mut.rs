@@ -0,0 +1,35 @@
+use crate::GetMut;
+
+/// Object-safe `GetMut` for implementations returning plain mutable references.
+///
+/// Implemented for every `'static` implementer of `GetMut` whose `ValueMut<'a>` is `&'a mut V`.
+pub trait DynGetMut<K, V> {
+  /// `get_mut` returning a mutable reference
+  fn get_mut(&mut self, k: K) -> Option<&mut V>;
+}
+
+impl<K, V, G: ?Sized + 'static + for<'a> GetMut<K, ValueMut<'a> = &'a mut V>> DynGetMut<K, V>
+  for G
+{
+  fn get_mut(&mut self, k: K) -> Option<&mut V> {
+    G::get_mut(self, k)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  use alloc::collections::VecDeque;
+
+  #[test]
+  fn boxed() {
+    let mut cols: Vec<Box<dyn DynGetMut<usize, i32>>> =
+      vec![Box::new(vec![1, 2]), Box::new(VecDeque::from([1, 2]))];
+    for c in cols.iter_mut() {
+      *c.get_mut(0).unwrap() += 10;
+      assert_eq!(c.get_mut(0), Some(&mut 11));
+      assert_eq!(c.get_mut(2), None);
+    }
+  }
+}
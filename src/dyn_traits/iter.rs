@@ -0,0 +1,46 @@
+use crate::Iter;
+use alloc::boxed::Box;
+
+/// Object-safe `Iter` for implementations yielding plain references.
+///
+/// Implemented for every `'static` implementer of `Iter` whose iterator yields `&'a V`. The returned iterator is boxed.
+pub trait DynIter<V> {
+  /// `iter` returning a boxed iterator
+  fn iter(&self) -> Box<dyn Iterator<Item = &V> + '_>;
+}
+
+impl<V, I> DynIter<V> for I
+where
+  I: ?Sized + 'static + Iter,
+  for<'a> I::Iter<'a>: private::RefIterator<'a, V>,
+{
+  fn iter(&self) -> Box<dyn Iterator<Item = &V> + '_> {
+    Box::new(I::iter(self))
+  }
+}
+
+mod private {
+  /// Names the lifetime of the yielded references, which can't be bound in a higher-ranked `Iterator<Item = &'a V>`.
+  pub trait RefIterator<'a, V: 'a>: Iterator<Item = &'a V> {}
+
+  impl<'a, V: 'a, I: Iterator<Item = &'a V>> RefIterator<'a, V> for I {}
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  use std::collections::{BTreeSet, LinkedList};
+
+  #[test]
+  fn boxed() {
+    let cols: Vec<Box<dyn DynIter<i32>>> = vec![
+      Box::new(vec![1, 2]),
+      Box::new(BTreeSet::from([1, 2])),
+      Box::new(LinkedList::from([1, 2])),
+    ];
+    for c in &cols {
+      assert_eq!(c.iter().sum::<i32>(), 3);
+    }
+  }
+}
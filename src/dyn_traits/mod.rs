@@ -0,0 +1,18 @@
+mod get;
+#[cfg(feature = "alloc")]
+mod get_guard;
+mod get_mut;
+#[cfg(feature = "alloc")]
+mod iter;
+
+pub use get::*;
+#[cfg(feature = "alloc")]
+pub use get_guard::*;
+pub use get_mut::*;
+#[cfg(feature = "alloc")]
+pub use iter::*;
+
+pub use crate::nosafety::{
+  Clear as DynClear, Insert as DynInsert, Len as DynLen, Pop as DynPop, Push as DynPush,
+  Remove as DynRemove,
+};
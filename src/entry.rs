@@ -0,0 +1,951 @@
+#[cfg(any(feature = "alloc", feature = "tinyvec"))]
+use crate::Len;
+#[cfg(feature = "alloc")]
+use crate::Safe;
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Unsafe;
+use crate::{GetMut, Insert, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{btree_map, BTreeMap, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+use core::ops::DerefMut;
+use core::ops::IndexMut;
+#[cfg(feature = "std")]
+use std::collections::{hash_map, HashMap};
+
+/// Provides in-place access to a key that may or may not be present in a collection, allowing it to be read, updated or filled with a single lookup.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::{BTreeMap, HashMap};
+/// use collectivity::Entry;
+///
+/// fn count<K>(
+///   col: &mut impl Entry<K, usize>,
+///   k: K
+/// ) {
+///   col.entry(k).and_modify(|v| *v += 1).or_insert(1);
+/// }
+///
+/// let mut m = HashMap::new();
+/// count(&mut m, "A");
+/// count(&mut m, "A");
+/// assert_eq!(m["A"], 2);
+///
+/// let mut m = BTreeMap::new();
+/// count(&mut m, "A");
+/// assert_eq!(m["A"], 1);
+///
+/// let mut v = vec![];
+/// count(&mut v, 0);
+/// count(&mut v, 0);
+/// assert_eq!(v, vec![2]);
+/// # }
+/// ```
+pub trait Entry<K, V> {
+  /// Indicates whether the `entry` method and the returned entry handles may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the handle to an entry that already holds a value.
+  type Occupied<'a>: OccupiedEntry<Value = V>
+  where
+    Self: 'a;
+  /// The type of the handle to an entry that doesn't hold a value yet.
+  type Vacant<'a>: VacantEntry<
+    Value = V,
+    ValueMut = <Self::Occupied<'a> as OccupiedEntry>::ValueMut,
+  >
+  where
+    Self: 'a;
+  /// Returns the entry at key `k`, which is either occupied or vacant.
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>>;
+}
+
+/// Handle to an entry that already holds a value.
+pub trait OccupiedEntry {
+  /// The type of the value held by the entry.
+  type Value;
+  /// The type of the mutable value returned once the handle is consumed. Either a mutable reference or a guard, depending on the implementer.
+  type ValueMut;
+  /// Returns a reference to the value held by the entry.
+  fn get(&self) -> &Self::Value;
+  /// Returns a mutable reference to the value held by the entry.
+  fn get_mut(&mut self) -> &mut Self::Value;
+  /// Consumes the handle and returns the mutable value bound to the lifetime of the collection.
+  fn into_mut(self) -> Self::ValueMut;
+  /// Replaces the value held by the entry and returns the previous one.
+  fn insert(&mut self, v: Self::Value) -> Self::Value;
+}
+
+/// Handle to an entry that doesn't hold a value yet.
+pub trait VacantEntry {
+  /// The type of the value that can be inserted into the entry.
+  type Value;
+  /// The type of the mutable value returned by `insert`. Either a mutable reference or a guard, depending on the implementer.
+  type ValueMut;
+  /// Inserts value `v` into the entry and returns the mutable value bound to the lifetime of the collection.
+  fn insert(self, v: Self::Value) -> Self::ValueMut;
+}
+
+/// An entry returned by [`Entry::entry`].
+#[derive(Debug)]
+pub enum EntryHandle<O, V> {
+  /// The entry already holds a value.
+  Occupied(O),
+  /// The entry doesn't hold a value yet.
+  Vacant(V),
+}
+
+impl<O: OccupiedEntry, V: VacantEntry<Value = O::Value, ValueMut = O::ValueMut>> EntryHandle<O, V> {
+  /// Inserts `v` if the entry is vacant and returns the mutable value.
+  pub fn or_insert(self, v: O::Value) -> O::ValueMut {
+    match self {
+      EntryHandle::Occupied(o) => o.into_mut(),
+      EntryHandle::Vacant(va) => va.insert(v),
+    }
+  }
+  /// Inserts the result of `f` if the entry is vacant and returns the mutable value. `f` is not called for occupied entries.
+  pub fn or_insert_with<F: FnOnce() -> O::Value>(self, f: F) -> O::ValueMut {
+    match self {
+      EntryHandle::Occupied(o) => o.into_mut(),
+      EntryHandle::Vacant(va) => va.insert(f()),
+    }
+  }
+  /// Inserts the default value if the entry is vacant and returns the mutable value.
+  pub fn or_default(self) -> O::ValueMut
+  where
+    O::Value: Default,
+  {
+    self.or_insert_with(Default::default)
+  }
+  /// Calls `f` with the held value if the entry is occupied and returns the entry for further chaining.
+  pub fn and_modify<F: FnOnce(&mut O::Value)>(mut self, f: F) -> Self {
+    if let EntryHandle::Occupied(o) = &mut self {
+      f(o.get_mut());
+    }
+    self
+  }
+}
+
+/// Occupied entry emulated for index-based collections.
+#[derive(Debug)]
+pub struct IndexOccupiedEntry<'a, C: ?Sized> {
+  col: &'a mut C,
+  k: usize,
+}
+
+/// Vacant entry emulated for index-based collections.
+///
+/// # Panics
+///
+/// `insert` panics when the key is past the end of the collection, in the same way the underlying `Insert` implementation does.
+#[derive(Debug)]
+pub struct IndexVacantEntry<'a, C: ?Sized> {
+  col: &'a mut C,
+  k: usize,
+}
+
+impl<'a, V: 'a, C: ?Sized + IndexMut<usize, Output = V>> OccupiedEntry
+  for IndexOccupiedEntry<'a, C>
+{
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    &self.col[self.k]
+  }
+  fn get_mut(&mut self) -> &mut V {
+    &mut self.col[self.k]
+  }
+  fn into_mut(self) -> &'a mut V {
+    &mut self.col[self.k]
+  }
+  fn insert(&mut self, v: V) -> V {
+    core::mem::replace(&mut self.col[self.k], v)
+  }
+}
+
+impl<'a, V: 'a, C: ?Sized + GetMut<usize, ValueMut<'a> = &'a mut V> + Insert<usize, V>> VacantEntry
+  for IndexVacantEntry<'a, C>
+{
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn insert(self, v: V) -> &'a mut V {
+    self.col.insert(self.k, v);
+    match self.col.get_mut(self.k) {
+      Some(v) => v,
+      None => panic!("Index out of bounds"),
+    }
+  }
+}
+
+#[cfg(any(feature = "alloc", feature = "tinyvec"))]
+fn index_entry<C: ?Sized + Len>(
+  col: &mut C,
+  k: usize,
+) -> EntryHandle<IndexOccupiedEntry<'_, C>, IndexVacantEntry<'_, C>> {
+  if k < col.len() {
+    EntryHandle::Occupied(IndexOccupiedEntry { col, k })
+  } else {
+    EntryHandle::Vacant(IndexVacantEntry { col, k })
+  }
+}
+
+/// Like `index_entry`, but hands occupied entries the underlying slice, for collections that only index through `DerefMut`.
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+fn slice_entry<V, C: ?Sized + DerefMut<Target = [V]>>(
+  col: &mut C,
+  k: usize,
+) -> EntryHandle<IndexOccupiedEntry<'_, [V]>, IndexVacantEntry<'_, C>> {
+  if k < col.len() {
+    EntryHandle::Occupied(IndexOccupiedEntry { col: &mut **col, k })
+  } else {
+    EntryHandle::Vacant(IndexVacantEntry { col, k })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Entry<usize, V> for Vec<V> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = IndexOccupiedEntry<'a, Self>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = IndexVacantEntry<'a, Self>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: usize) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    index_entry(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Entry<usize, V> for VecDeque<V> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = IndexOccupiedEntry<'a, Self>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = IndexVacantEntry<'a, Self>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: usize) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    index_entry(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, K: Ord, V> OccupiedEntry for btree_map::OccupiedEntry<'a, K, V> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut V {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: V) -> V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, K: Ord, V> VacantEntry for btree_map::VacantEntry<'a, K, V> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn insert(self, v: V) -> &'a mut V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Entry<K, V> for BTreeMap<K, V> {
+  type Safety = Safe;
+  type Occupied<'a>
+    = btree_map::OccupiedEntry<'a, K, V>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = btree_map::VacantEntry<'a, K, V>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match BTreeMap::entry(self, k) {
+      btree_map::Entry::Occupied(o) => EntryHandle::Occupied(o),
+      btree_map::Entry::Vacant(v) => EntryHandle::Vacant(v),
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V> OccupiedEntry for hash_map::OccupiedEntry<'a, K, V> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut V {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: V) -> V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, V> VacantEntry for hash_map::VacantEntry<'a, K, V> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn insert(self, v: V) -> &'a mut V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Entry<K, V> for HashMap<K, V, S> {
+  type Safety = Safe;
+  type Occupied<'a>
+    = hash_map::OccupiedEntry<'a, K, V>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = hash_map::VacantEntry<'a, K, V>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match HashMap::entry(self, k) {
+      hash_map::Entry::Occupied(o) => EntryHandle::Occupied(o),
+      hash_map::Entry::Vacant(v) => EntryHandle::Vacant(v),
+    }
+  }
+}
+
+#[cfg(feature = "dashmap")]
+use dashmap::{mapref::entry as dashmap_entry, mapref::one::RefMut, DashMap};
+
+#[cfg(feature = "dashmap")]
+impl<'a, K: Eq + Hash, V, S: BuildHasher> OccupiedEntry
+  for dashmap_entry::OccupiedEntry<'a, K, V, S>
+{
+  type Value = V;
+  type ValueMut = RefMut<'a, K, V, S>;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> RefMut<'a, K, V, S> {
+    self.into_ref()
+  }
+  fn insert(&mut self, v: V) -> V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<'a, K: Eq + Hash, V, S: BuildHasher> VacantEntry for dashmap_entry::VacantEntry<'a, K, V, S> {
+  type Value = V;
+  type ValueMut = RefMut<'a, K, V, S>;
+  fn insert(self, v: V) -> RefMut<'a, K, V, S> {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Entry<K, V> for DashMap<K, V, S> {
+  type Safety = Safe;
+  type Occupied<'a>
+    = dashmap_entry::OccupiedEntry<'a, K, V, S>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = dashmap_entry::VacantEntry<'a, K, V, S>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match DashMap::entry(self, k) {
+      dashmap_entry::Entry::Occupied(o) => EntryHandle::Occupied(o),
+      dashmap_entry::Entry::Vacant(v) => EntryHandle::Vacant(v),
+    }
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::{map as serde_json_map, Value as SeV};
+
+#[cfg(feature = "serde_json")]
+impl<'a> OccupiedEntry for serde_json_map::OccupiedEntry<'a> {
+  type Value = SeV;
+  type ValueMut = &'a mut SeV;
+  fn get(&self) -> &SeV {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut SeV {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut SeV {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: SeV) -> SeV {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl<'a> VacantEntry for serde_json_map::VacantEntry<'a> {
+  type Value = SeV;
+  type ValueMut = &'a mut SeV;
+  fn insert(self, v: SeV) -> &'a mut SeV {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl Entry<String, SeV> for SeV {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = serde_json_map::OccupiedEntry<'a>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = serde_json_map::VacantEntry<'a>
+  where
+    Self: 'a;
+
+  /// # Panics
+  ///
+  /// Panics if the value is not an object.
+  fn entry<'a>(&'a mut self, k: String) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match self {
+      SeV::Object(o) => match o.entry(k) {
+        serde_json_map::Entry::Occupied(o) => EntryHandle::Occupied(o),
+        serde_json_map::Entry::Vacant(v) => EntryHandle::Vacant(v),
+      },
+      _ => panic!("Value is not an object"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{cow::Cow, BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a, K, V> OccupiedEntry for halfbrown::OccupiedEntry<'a, K, V, halfbrown::DefaultHashBuilder> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut V {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: V) -> V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'a, K: Hash, V> VacantEntry
+  for halfbrown::VacantEntry<'a, K, V, halfbrown::DefaultHashBuilder>
+{
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn insert(self, v: V) -> &'a mut V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> Entry<Cow<'v, str>, SBV<'v>> for SBV<'v> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = halfbrown::OccupiedEntry<'a, Cow<'v, str>, SBV<'v>, halfbrown::DefaultHashBuilder>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = halfbrown::VacantEntry<'a, Cow<'v, str>, SBV<'v>, halfbrown::DefaultHashBuilder>
+  where
+    Self: 'a;
+
+  /// # Panics
+  ///
+  /// Panics if the value is not an object.
+  fn entry<'a>(&'a mut self, k: Cow<'v, str>) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match self {
+      SBV::Object(o) => match o.entry(k) {
+        halfbrown::Entry::Occupied(o) => EntryHandle::Occupied(o),
+        halfbrown::Entry::Vacant(v) => EntryHandle::Vacant(v),
+      },
+      _ => panic!("Value is not an object"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Entry<String, SOV> for SOV {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = halfbrown::OccupiedEntry<'a, String, SOV, halfbrown::DefaultHashBuilder>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = halfbrown::VacantEntry<'a, String, SOV, halfbrown::DefaultHashBuilder>
+  where
+    Self: 'a;
+
+  /// # Panics
+  ///
+  /// Panics if the value is not an object.
+  fn entry<'a>(&'a mut self, k: String) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match self {
+      SOV::Object(o) => match o.entry(k) {
+        halfbrown::Entry::Occupied(o) => EntryHandle::Occupied(o),
+        halfbrown::Entry::Vacant(v) => EntryHandle::Vacant(v),
+      },
+      _ => panic!("Value is not an object"),
+    }
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{map as index_map, IndexMap};
+
+#[cfg(feature = "indexmap")]
+impl<'a, K, V> OccupiedEntry for index_map::OccupiedEntry<'a, K, V> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut V {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: V) -> V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'a, K, V> VacantEntry for index_map::VacantEntry<'a, K, V> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn insert(self, v: V) -> &'a mut V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Entry<K, V> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Occupied<'a>
+    = index_map::OccupiedEntry<'a, K, V>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = index_map::VacantEntry<'a, K, V>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match IndexMap::entry(self, k) {
+      index_map::Entry::Occupied(o) => EntryHandle::Occupied(o),
+      index_map::Entry::Vacant(v) => EntryHandle::Vacant(v),
+    }
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+use hashbrown::hash_map::{RawEntryMut, RawOccupiedEntryMut, RawVacantEntryMut};
+
+/// Vacant entry of a `hashbrown::HashMap`, holding the key together with the hash computed during the lookup, so that inserting doesn't hash it again.
+#[cfg(feature = "hashbrown")]
+#[derive(Debug)]
+pub struct RawVacantEntry<'a, K, V, S> {
+  entry: RawVacantEntryMut<'a, K, V, S>,
+  hash: u64,
+  k: K,
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'a, K, V, S> OccupiedEntry for RawOccupiedEntryMut<'a, K, V, S> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut V {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: V) -> V {
+    self.insert(v)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'a, K: Hash, V, S: BuildHasher> VacantEntry for RawVacantEntry<'a, K, V, S> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn insert(self, v: V) -> &'a mut V {
+    self.entry.insert_hashed_nocheck(self.hash, self.k, v).1
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Entry<K, V> for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Occupied<'a>
+    = RawOccupiedEntryMut<'a, K, V, S>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = RawVacantEntry<'a, K, V, S>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    let hash = self.hasher().hash_one(&k);
+    match self.raw_entry_mut().from_key_hashed_nocheck(hash, &k) {
+      RawEntryMut::Occupied(o) => EntryHandle::Occupied(o),
+      RawEntryMut::Vacant(entry) => EntryHandle::Vacant(RawVacantEntry { entry, hash, k }),
+    }
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Entry<usize, V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = IndexOccupiedEntry<'a, [V]>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = IndexVacantEntry<'a, Self>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: usize) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    slice_entry(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Entry<usize, V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = IndexOccupiedEntry<'a, [V]>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = IndexVacantEntry<'a, Self>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: usize) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    slice_entry(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+use heapless::{
+  Entry as HeaplessEntry, OccupiedEntry as HeaplessOccupiedEntry,
+  VacantEntry as HeaplessVacantEntry,
+};
+
+#[cfg(feature = "heapless")]
+impl<'a, K: Eq + Hash, V, const N: usize> OccupiedEntry for HeaplessOccupiedEntry<'a, K, V, N> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  fn get(&self) -> &V {
+    self.get()
+  }
+  fn get_mut(&mut self) -> &mut V {
+    self.get_mut()
+  }
+  fn into_mut(self) -> &'a mut V {
+    self.into_mut()
+  }
+  fn insert(&mut self, v: V) -> V {
+    core::mem::replace(self.get_mut(), v)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<'a, K: Eq + Hash, V, const N: usize> VacantEntry for HeaplessVacantEntry<'a, K, V, N> {
+  type Value = V;
+  type ValueMut = &'a mut V;
+  /// Panics if the map is full
+  fn insert(self, v: V) -> &'a mut V {
+    match self.insert(v) {
+      Ok(v) => v,
+      Err(_) => panic!("Capacity exceeded"),
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Entry<K, V>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = HeaplessOccupiedEntry<'a, K, V, N>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = HeaplessVacantEntry<'a, K, V, N>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: K) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    match heapless::IndexMap::entry(self, k) {
+      HeaplessEntry::Occupied(o) => EntryHandle::Occupied(o),
+      HeaplessEntry::Vacant(v) => EntryHandle::Vacant(v),
+    }
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Entry<usize, A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = IndexOccupiedEntry<'a, Self>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = IndexVacantEntry<'a, Self>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: usize) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    index_entry(self, k)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Entry<usize, A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  type Occupied<'a>
+    = IndexOccupiedEntry<'a, Self>
+  where
+    Self: 'a;
+  type Vacant<'a>
+    = IndexVacantEntry<'a, Self>
+  where
+    Self: 'a;
+
+  fn entry<'a>(&'a mut self, k: usize) -> EntryHandle<Self::Occupied<'a>, Self::Vacant<'a>> {
+    index_entry(self, k)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn vec() {
+    let mut v = vec![1];
+    *<Vec<i32> as Entry<usize, i32>>::entry(&mut v, 0).or_insert(0) += 1;
+    <Vec<i32> as Entry<usize, i32>>::entry(&mut v, 1).or_insert_with(|| 5);
+    assert_eq!(v, vec![2, 5]);
+  }
+  #[test]
+  #[should_panic]
+  fn vec_out_of_bounds() {
+    <Vec<i32> as Entry<usize, i32>>::entry(&mut vec![], 1).or_insert(0);
+  }
+  #[test]
+  fn vec_deque() {
+    let mut v = VecDeque::new();
+    <VecDeque<i32> as Entry<usize, i32>>::entry(&mut v, 0)
+      .and_modify(|v| *v += 1)
+      .or_insert(1);
+    <VecDeque<i32> as Entry<usize, i32>>::entry(&mut v, 0)
+      .and_modify(|v| *v += 1)
+      .or_insert(1);
+    assert_eq!(v, VecDeque::from([2]));
+  }
+  #[test]
+  fn b_tree_map() {
+    let mut m = BTreeMap::new();
+    <BTreeMap<i32, i32> as Entry<i32, i32>>::entry(&mut m, 0).or_default();
+    *<BTreeMap<i32, i32> as Entry<i32, i32>>::entry(&mut m, 0).or_insert(5) += 1;
+    assert_eq!(m[&0], 1);
+  }
+  #[test]
+  fn hash_map() {
+    let mut m = HashMap::new();
+    match <HashMap<i32, i32> as Entry<i32, i32>>::entry(&mut m, 0) {
+      EntryHandle::Occupied(_) => unreachable!(),
+      EntryHandle::Vacant(v) => {
+        v.insert(1);
+      }
+    }
+    match <HashMap<i32, i32> as Entry<i32, i32>>::entry(&mut m, 0) {
+      EntryHandle::Occupied(mut o) => assert_eq!(o.insert(2), 1),
+      EntryHandle::Vacant(_) => unreachable!(),
+    }
+    assert_eq!(m[&0], 2);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dash_map() {
+    let mut m = DashMap::new();
+    <DashMap<i32, i32> as Entry<i32, i32>>::entry(&mut m, 0).or_insert(1);
+    <DashMap<i32, i32> as Entry<i32, i32>>::entry(&mut m, 0)
+      .and_modify(|v| *v += 1)
+      .or_insert(1);
+    assert_eq!(m.get(&0).map(|v| *v), Some(2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut o = SeV::Object(Default::default());
+    <SeV as Entry<String, SeV>>::entry(&mut o, "a".into()).or_insert(SeV::Null);
+    <SeV as Entry<String, SeV>>::entry(&mut o, "a".into()).or_insert(SeV::Bool(true));
+    assert_eq!(o.get("a"), Some(&SeV::Null));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  use crate::Get;
+  #[test]
+  fn simd_json_borrowed() {
+    let mut o = SBV::Object(Default::default());
+    <SBV as Entry<Cow<'_, str>, SBV>>::entry(&mut o, "a".into())
+      .or_insert(SBV::Static(simd_json::StaticNode::Null));
+    assert_eq!(o.get("a"), Some(&SBV::Static(simd_json::StaticNode::Null)));
+  }
+  #[test]
+  fn simd_json_owned() {
+    let mut o = SOV::Object(Default::default());
+    <SOV as Entry<String, SOV>>::entry(&mut o, "a".into())
+      .or_insert_with(|| SOV::Static(simd_json::StaticNode::Null));
+    assert_eq!(o.get("a"), Some(&SOV::Static(simd_json::StaticNode::Null)));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::from([("B", 0)]);
+    <IndexMap<&str, i32> as Entry<&str, i32>>::entry(&mut m, "A").or_insert(1);
+    *<IndexMap<&str, i32> as Entry<&str, i32>>::entry(&mut m, "B").or_insert(5) += 1;
+    assert_eq!(m, IndexMap::from([("B", 1), ("A", 1)]));
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&"B", &"A"]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([("B", 0)]);
+    <hashbrown::HashMap<&str, i32> as Entry<&str, i32>>::entry(&mut m, "A").or_insert(1);
+    *<hashbrown::HashMap<&str, i32> as Entry<&str, i32>>::entry(&mut m, "B").or_insert(5) += 1;
+    <hashbrown::HashMap<&str, i32> as Entry<&str, i32>>::entry(&mut m, "A").and_modify(|v| *v += 2);
+    assert_eq!(m, hashbrown::HashMap::from([("A", 3), ("B", 1)]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 4>::from_iter([1]);
+    *Entry::entry(&mut v, 0).or_insert(5) += 1;
+    Entry::entry(&mut v, 1).or_insert(5);
+    assert_eq!(v.as_slice(), &[2, 5]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless_vec() {
+    let mut v = heapless::Vec::<i32, 4>::from_slice(&[1]).unwrap();
+    *Entry::entry(&mut v, 0).or_insert(5) += 1;
+    Entry::entry(&mut v, 1).or_insert(5);
+    assert_eq!(v.as_slice(), &[2, 5]);
+  }
+  #[test]
+  fn heapless() {
+    let mut m = heapless::FnvIndexMap::<&str, i32, 2>::new();
+    *Entry::entry(&mut m, "a").or_insert(0) += 1;
+    *Entry::entry(&mut m, "a").or_insert(0) += 1;
+    assert_eq!(m.get("a"), Some(&2));
+  }
+  #[test]
+  #[should_panic]
+  fn heapless_full() {
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::from_iter([(1, 1), (2, 2)]);
+    Entry::entry(&mut m, 3).or_insert(3);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 4]>::from_iter([1]);
+    *Entry::entry(&mut v, 0).or_insert(5) += 1;
+    Entry::entry(&mut v, 1).or_insert(5);
+    assert_eq!(v.as_slice(), &[2, 5]);
+  }
+}
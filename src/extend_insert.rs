@@ -0,0 +1,436 @@
+#[cfg(feature = "alloc")]
+use crate::Safe;
+use crate::{Insert, SafetyMarker, Unsafe};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Provides the ability to insert a batch of key-value pairs into a collection.
+///
+/// Implementations delegate to the native `extend` of the underlying container where available, which typically reserves space based on the size hint of the iterator. Other containers fall back to inserting the pairs one by one. The iterator is passed as a trait object, so that dynamically dispatched collections pay for a single virtual call per batch.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::ExtendInsert;
+///
+/// fn insert_all(
+///   col: &mut impl ExtendInsert<usize, &'static str>,
+///   values: &[(usize, &'static str)]
+/// ) {
+///   col.extend_insert(&mut values.iter().copied());
+/// }
+///
+/// let mut v = vec![];
+/// insert_all(&mut v, &[(0, "b"), (0, "a")]);
+/// assert_eq!(v, vec!["a", "b"]);
+///
+/// let mut m = HashMap::new();
+/// insert_all(&mut m, &[(0, "b"), (0, "a")]);
+/// assert_eq!(m, HashMap::from([(0, "a")]));
+/// # }
+/// ```
+pub trait ExtendInsert<K, V> {
+  /// Indicates whether the `extend_insert` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Inserts all key-value pairs yielded by `iter` in order, with the same semantics as `Insert::insert`.
+  ///
+  /// # Panics
+  ///
+  /// May panic, e.g. when an index is out of bounds.
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>);
+}
+
+impl<K, V, I: ExtendInsert<K, V>> ExtendInsert<K, V> for &mut I {
+  type Safety = <I as ExtendInsert<K, V>>::Safety;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    <I as ExtendInsert<K, V>>::extend_insert(self, iter)
+  }
+}
+
+impl<V, const N: usize> ExtendInsert<usize, V> for [V; N] {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    iter.for_each(|(k, v)| Insert::insert(self, k, v))
+  }
+}
+
+impl<V> ExtendInsert<usize, V> for [V] {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    iter.for_each(|(k, v)| Insert::insert(self, k, v))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ExtendInsert<usize, V> for Vec<V> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    self.reserve(iter.size_hint().0);
+    iter.for_each(|(k, v)| self.insert(k, v))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ExtendInsert<usize, V> for VecDeque<V> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    self.reserve(iter.size_hint().0);
+    iter.for_each(|(k, v)| self.insert(k, v))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ExtendInsert<usize, V> for LinkedList<V> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    iter.for_each(|(k, v)| Insert::insert(self, k, v))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> ExtendInsert<K, V> for BTreeMap<K, V> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> ExtendInsert<K, ()> for BTreeSet<K> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, ())>) {
+    self.extend(iter.map(|(k, _)| k))
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> ExtendInsert<K, V> for HashMap<K, V, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> ExtendInsert<K, ()> for HashSet<K, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, ())>) {
+    self.extend(iter.map(|(k, _)| k))
+  }
+}
+
+#[cfg(feature = "dashmap")]
+use dashmap::{DashMap, DashSet};
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> ExtendInsert<K, V> for DashMap<K, V, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> ExtendInsert<K, ()> for DashSet<K, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, ())>) {
+    self.extend(iter.map(|(k, _)| k))
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl ExtendInsert<usize, SeV> for SeV {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, SeV)>) {
+    match self {
+      SeV::Array(a) => iter.for_each(|(k, v)| a.insert(k, v)),
+      _ => panic!("Value is not an array"),
+    }
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl ExtendInsert<String, SeV> for SeV {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (String, SeV)>) {
+    match self {
+      SeV::Object(o) => o.extend(iter),
+      _ => panic!("Value is not an object"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{cow::Cow, BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a> ExtendInsert<usize, SBV<'a>> for SBV<'a> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, SBV<'a>)>) {
+    match self {
+      SBV::Array(a) => iter.for_each(|(k, v)| a.insert(k, v)),
+      _ => panic!("Value is not an array"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'a> ExtendInsert<Cow<'a, str>, SBV<'a>> for SBV<'a> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (Cow<'a, str>, SBV<'a>)>) {
+    match self {
+      SBV::Object(o) => iter.for_each(|(k, v)| {
+        o.insert(k, v);
+      }),
+      _ => panic!("Value is not an object"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl ExtendInsert<usize, SOV> for SOV {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, SOV)>) {
+    match self {
+      SOV::Array(a) => iter.for_each(|(k, v)| a.insert(k, v)),
+      _ => panic!("Value is not an array"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl ExtendInsert<String, SOV> for SOV {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (String, SOV)>) {
+    match self {
+      SOV::Object(o) => iter.for_each(|(k, v)| {
+        o.insert(k, v);
+      }),
+      _ => panic!("Value is not an object"),
+    }
+  }
+}
+
+#[cfg(feature = "smallvec")]
+use smallvec::{Array, SmallVec};
+
+#[cfg(feature = "smallvec")]
+impl<V, A: Array<Item = V>> ExtendInsert<usize, V> for SmallVec<A> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    self.reserve(iter.size_hint().0);
+    iter.for_each(|(k, v)| self.insert(k, v))
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> ExtendInsert<K, V> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> ExtendInsert<K, ()> for IndexSet<K, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, ())>) {
+    self.extend(iter.map(|(k, _)| k))
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> ExtendInsert<K, V> for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> ExtendInsert<K, ()> for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, ())>) {
+    self.extend(iter.map(|(k, _)| k))
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> ExtendInsert<usize, V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    iter.for_each(|(k, v)| <Self as Insert<usize, V>>::insert(self, k, v))
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> ExtendInsert<usize, V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, V)>) {
+    iter.for_each(|(k, v)| <Self as Insert<usize, V>>::insert(self, k, v))
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> ExtendInsert<K, V>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more new keys than the remaining capacity
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> ExtendInsert<usize, A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, A::Item)>) {
+    iter.for_each(|(k, v)| <Self as Insert<usize, A::Item>>::insert(self, k, v))
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> ExtendInsert<usize, A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (usize, A::Item)>) {
+    iter.for_each(|(k, v)| <Self as Insert<usize, A::Item>>::insert(self, k, v))
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = [0, 1, 2];
+    <[i32; 3] as ExtendInsert<usize, i32>>::extend_insert(
+      &mut v,
+      &mut [(0, 3), (2, 4)].into_iter(),
+    );
+    assert_eq!(v, [3, 1, 4]);
+    let mut v = vec![];
+    <Vec<i32> as ExtendInsert<usize, i32>>::extend_insert(
+      &mut v,
+      &mut [(0, 1), (0, 2)].into_iter(),
+    );
+    assert_eq!(v, vec![2, 1]);
+    let mut v = VecDeque::new();
+    <VecDeque<i32> as ExtendInsert<usize, i32>>::extend_insert(
+      &mut v,
+      &mut [(0, 1), (1, 2)].into_iter(),
+    );
+    assert_eq!(v, VecDeque::from([1, 2]));
+    let mut l = LinkedList::new();
+    <LinkedList<i32> as ExtendInsert<usize, i32>>::extend_insert(
+      &mut l,
+      &mut [(0, 1), (0, 2)].into_iter(),
+    );
+    assert_eq!(l, LinkedList::from([2, 1]));
+    let mut m = BTreeMap::new();
+    <BTreeMap<i32, i32> as ExtendInsert<i32, i32>>::extend_insert(
+      &mut m,
+      &mut [(0, 1)].into_iter(),
+    );
+    assert_eq!(m[&0], 1);
+    let mut s = BTreeSet::new();
+    <BTreeSet<i32> as ExtendInsert<i32, ()>>::extend_insert(&mut s, &mut [(0, ())].into_iter());
+    assert!(s.contains(&0));
+    let mut m = HashMap::new();
+    <HashMap<i32, i32> as ExtendInsert<i32, i32>>::extend_insert(&mut m, &mut [(0, 1)].into_iter());
+    assert_eq!(m[&0], 1);
+    let mut s = HashSet::new();
+    <HashSet<i32> as ExtendInsert<i32, ()>>::extend_insert(&mut s, &mut [(0, ())].into_iter());
+    assert!(s.contains(&0));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dashmap() {
+    let mut m = DashMap::new();
+    <DashMap<i32, i32> as ExtendInsert<i32, i32>>::extend_insert(&mut m, &mut [(0, 1)].into_iter());
+    assert_eq!(m.get(&0).map(|v| *v), Some(1));
+    let mut s = DashSet::new();
+    <DashSet<i32> as ExtendInsert<i32, ()>>::extend_insert(&mut s, &mut [(0, ())].into_iter());
+    assert!(s.contains(&0));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut a = SeV::Array(Default::default());
+    <SeV as ExtendInsert<usize, SeV>>::extend_insert(&mut a, &mut [(0, SeV::Null)].into_iter());
+    assert_eq!(a.get(0), Some(&SeV::Null));
+    let mut o = SeV::Object(Default::default());
+    <SeV as ExtendInsert<String, SeV>>::extend_insert(
+      &mut o,
+      &mut [("a".into(), SeV::Null)].into_iter(),
+    );
+    assert_eq!(o.get("a"), Some(&SeV::Null));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::new();
+    <IndexMap<i32, i32> as ExtendInsert<i32, i32>>::extend_insert(
+      &mut m,
+      &mut [(1, 0), (0, 1)].into_iter(),
+    );
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&1, &0]);
+    let mut s = IndexSet::new();
+    <IndexSet<i32> as ExtendInsert<i32, ()>>::extend_insert(
+      &mut s,
+      &mut [(1, ()), (0, ())].into_iter(),
+    );
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&1, &0]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::new();
+    <hashbrown::HashMap<i32, i32> as ExtendInsert<i32, i32>>::extend_insert(
+      &mut m,
+      &mut [(0, 1)].into_iter(),
+    );
+    assert_eq!(m, hashbrown::HashMap::from([(0, 1)]));
+    let mut s = hashbrown::HashSet::new();
+    <hashbrown::HashSet<i32> as ExtendInsert<i32, ()>>::extend_insert(
+      &mut s,
+      &mut [(0, ())].into_iter(),
+    );
+    assert_eq!(s, hashbrown::HashSet::from([0]));
+  }
+}
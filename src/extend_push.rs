@@ -0,0 +1,271 @@
+#[cfg(feature = "alloc")]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Unsafe;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// Provides the ability to push a batch of values into a collection.
+///
+/// Implementations delegate to the native `extend` of the underlying container where available, which typically reserves space based on the size hint of the iterator. Other containers fall back to pushing the values one by one. The iterator is passed as a trait object, so that dynamically dispatched collections pay for a single virtual call per batch.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::VecDeque;
+/// use collectivity::ExtendPush;
+///
+/// fn push_all(
+///   col: &mut impl ExtendPush<i32>,
+///   values: &[i32]
+/// ) {
+///   col.extend_push(&mut values.iter().copied());
+/// }
+///
+/// let mut v = vec![];
+/// push_all(&mut v, &[0, 1]);
+/// assert_eq!(v, vec![0, 1]);
+///
+/// let mut v = VecDeque::new();
+/// push_all(&mut v, &[0, 1]);
+/// assert_eq!(v, VecDeque::from([0, 1]));
+/// # }
+/// ```
+pub trait ExtendPush<V> {
+  /// Indicates whether the `extend_push` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Pushes all values yielded by `iter` to the collection, with the same semantics as `Push::push`.
+  ///
+  /// # Panics
+  ///
+  /// May panic, typically when the maximum collection size is exceeded.
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>);
+}
+
+impl<V, P: ExtendPush<V>> ExtendPush<V> for &mut P {
+  type Safety = <P as ExtendPush<V>>::Safety;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    <P as ExtendPush<V>>::extend_push(self, iter)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ExtendPush<V> for Vec<V> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ExtendPush<V> for VecDeque<V> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> ExtendPush<V> for BinaryHeap<V> {
+  type Safety = Safe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> ExtendPush<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl ExtendPush<SeV> for SeV {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = SeV>) {
+    match self {
+      SeV::Array(a) => a.extend(iter),
+      _ => panic!("Value is not an array"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a> ExtendPush<SBV<'a>> for SBV<'a> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = SBV<'a>>) {
+    match self {
+      SBV::Array(a) => a.extend(iter),
+      _ => panic!("Value is not an array"),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl ExtendPush<SOV> for SOV {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = SOV>) {
+    match self {
+      SOV::Array(a) => a.extend(iter),
+      _ => panic!("Value is not an array"),
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+use slab::Slab;
+
+#[cfg(feature = "slab")]
+impl<V> ExtendPush<V> for Slab<V> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.reserve(iter.size_hint().0);
+    iter.for_each(|v| crate::Push::push(self, v));
+  }
+}
+
+#[cfg(feature = "smallvec")]
+use smallvec::{Array, SmallVec};
+
+#[cfg(feature = "smallvec")]
+impl<V, A: Array<Item = V>> ExtendPush<V> for SmallVec<A> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> ExtendPush<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> ExtendPush<V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> ExtendPush<V> for heapless::Deque<V, N> {
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the remaining capacity
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    iter.for_each(|v| {
+      if heapless::Deque::push_back(self, v).is_err() {
+        panic!("Capacity exceeded")
+      }
+    })
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> ExtendPush<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Unsafe;
+  /// Panics if the iterator yields more values than the remaining capacity
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    iter.for_each(|v| {
+      if heapless::BinaryHeap::push(self, v).is_err() {
+        panic!("Capacity exceeded")
+      }
+    })
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> ExtendPush<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = A::Item>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> ExtendPush<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = A::Item>) {
+    self.extend(iter)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = vec![0];
+    <Vec<i32> as ExtendPush<i32>>::extend_push(&mut v, &mut (1..3));
+    assert_eq!(v, vec![0, 1, 2]);
+    let mut v = VecDeque::new();
+    <VecDeque<i32> as ExtendPush<i32>>::extend_push(&mut v, &mut (0..2));
+    assert_eq!(v, VecDeque::from([0, 1]));
+    let mut h = BinaryHeap::new();
+    <BinaryHeap<i32> as ExtendPush<i32>>::extend_push(&mut h, &mut (0..2));
+    assert_eq!(h.peek(), Some(&1));
+    let mut l = LinkedList::new();
+    <LinkedList<i32> as ExtendPush<i32>>::extend_push(&mut l, &mut (0..2));
+    assert_eq!(l, LinkedList::from([0, 1]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut a = SeV::Array(vec![]);
+    <SeV as ExtendPush<SeV>>::extend_push(&mut a, &mut [SeV::Null].into_iter());
+    assert_eq!(a, SeV::Array(vec![SeV::Null]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = Slab::new();
+    <Slab<i32> as ExtendPush<i32>>::extend_push(&mut s, &mut (5..7));
+    assert_eq!(s.len(), 2);
+    assert_eq!(s[1], 6);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = SmallVec::<[i32; 2]>::new();
+    <SmallVec<[i32; 2]> as ExtendPush<i32>>::extend_push(&mut sv, &mut (0..3));
+    assert_eq!(sv.as_slice(), &[0, 1, 2]);
+  }
+}
@@ -0,0 +1,134 @@
+use crate::{Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Provides access to the entry with the smallest key of an ordered collection.
+///
+/// Maps yield key-value pairs, while other collections yield their values. `BinaryHeap` only supports `Last`, as it only exposes its greatest value. `heapless::BinaryHeap` supports `First` when it is a min-heap.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{BTreeMap, BTreeSet};
+/// use collectivity::{First, Sorted};
+///
+/// fn first<'a, C: First<i32, Value<'a> = &'a i32>>(col: &'a C) -> Option<&'a i32> {
+///   col.first()
+/// }
+///
+/// assert_eq!(first(&BTreeSet::from([2, 1, 3])), Some(&1));
+/// assert_eq!(first(&Sorted::new([2, 1, 3])), Some(&1));
+/// assert_eq!(BTreeMap::from([(1, "a"), (2, "b")]).first(), Some((&1, &"a")));
+/// # }
+/// ```
+pub trait First<V> {
+  /// Indicates whether the `first` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the value returned by `first`.
+  type Value<'a>
+  where
+    Self: 'a;
+  /// Returns the `Option`-wrapped entry with the smallest key, or `None` if the collection is empty.
+  fn first<'a>(&'a self) -> Option<Self::Value<'a>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> First<(K, V)> for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Value<'a>
+    = (&'a K, &'a V)
+  where
+    Self: 'a;
+
+  fn first(&self) -> Option<(&K, &V)> {
+    self.first_key_value()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> First<K> for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn first(&self) -> Option<&K> {
+    alloc::collections::BTreeSet::first(self)
+  }
+}
+
+impl<V: Ord, const N: usize> First<V> for Sorted<[V; N]> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn first(&self) -> Option<&V> {
+    <[V]>::first(&self[..])
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> First<V> for Sorted<Vec<V>> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn first(&self) -> Option<&V> {
+    <[V]>::first(&self[..])
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, const N: usize> First<V> for heapless::BinaryHeap<V, heapless::binary_heap::Min, N> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn first(&self) -> Option<&V> {
+    heapless::BinaryHeap::peek(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, i32> as First<_>>::first(&Default::default()),
+      None
+    );
+    assert_eq!(
+      <alloc::collections::BTreeSet<i32> as First<_>>::first(&[1, 3, 2].into()),
+      Some(&1)
+    );
+  }
+  #[test]
+  fn sorted() {
+    assert_eq!(
+      <Sorted<Vec<i32>> as First<_>>::first(&Sorted::new(vec![1, 3, 2])),
+      Some(&1)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Min, 4>::new();
+    h.push(2).unwrap();
+    h.push(1).unwrap();
+    assert_eq!(First::first(&h), Some(&1));
+  }
+}
@@ -1,10 +1,18 @@
-#[cfg(feature = "std")]
-use core::hash::Hash;
+use crate::{ComplexityMarker, Constant, Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use crate::{Linear, Logarithmic};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use core::borrow::Borrow;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
 
 /// Provides safe access to a value at a specified key.
 ///
 /// ## Examples
 /// ```
+/// # #[cfg(feature = "alloc")] {
 /// use std::collections::BTreeMap;
 /// use collectivity::Get;
 ///
@@ -19,8 +27,13 @@ use core::hash::Hash;
 /// assert_eq!(get(&v, 0), Some(&0));
 /// let m = BTreeMap::from([("A", 1)]);
 /// assert_eq!(get(&m, &"A"), Some(&1));
+/// # }
 /// ```
 pub trait Get<K> {
+  /// Indicates whether the `get` and `contains` methods may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Indicates the time complexity of the `get` and `contains` methods in a particular implementation.
+  type Complexity: ComplexityMarker;
   /// The type of the value returned by `get`.
   type Value<'a>
   where
@@ -28,16 +41,15 @@ pub trait Get<K> {
   /// Returns the `Option`-wrapped value, or `None` if `k` is missing. Both the argument and the return value can be either borrowed or owned, depending on the implementer.
   fn get<'a>(&'a self, k: K) -> Option<Self::Value<'a>>;
   /// Returns `true` if the `k` exists in the collection and `false` otherwise.
-  fn contains<'a>(&'a self, k: K) -> bool {
+  fn contains(&self, k: K) -> bool {
     self.get(k).is_some()
   }
 }
 
 // impl<'g, K, G: Get<K>> Get<K> for &'g G {
-//   type Value<'a>
+//   type Value<'a> = <G as Get<K>>::Value<'a>
 //   where
-//     Self: 'a,
-//   = <G as Get<K>>::Value<'a>;
+//     Self: 'a;
 
 //   fn get<'a>(&'a self, k: K) -> Option<Self::Value<'a>> {
 //     <G as Get<K>>::get(self, k)
@@ -45,10 +57,12 @@ pub trait Get<K> {
 // }
 
 impl<'k, G: Get<usize>> Get<&'k usize> for G {
+  type Safety = <G as Get<usize>>::Safety;
+  type Complexity = <G as Get<usize>>::Complexity;
   type Value<'a>
+    = <G as Get<usize>>::Value<'a>
   where
-    Self: 'a,
-  = <G as Get<usize>>::Value<'a>;
+    Self: 'a;
 
   fn get<'a>(&'a self, k: &'k usize) -> Option<Self::Value<'a>> {
     <G as Get<usize>>::get(self, *k)
@@ -56,151 +70,200 @@ impl<'k, G: Get<usize>> Get<&'k usize> for G {
 }
 
 impl<V> Get<usize> for [V] {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: usize) -> Option<&'a V> {
+  fn get(&self, k: usize) -> Option<&V> {
     <[V]>::get(self, k)
   }
 }
 
 impl<V> Get<usize> for &[V] {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: usize) -> Option<&'a V> {
+  fn get(&self, k: usize) -> Option<&V> {
     <[V]>::get(self, k)
   }
 }
 
 impl<V, const N: usize> Get<usize> for [V; N] {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: usize) -> Option<&'a V> {
+  fn get(&self, k: usize) -> Option<&V> {
     <[V]>::get(self, k)
   }
 }
 
+impl<C: Get<usize>> Get<usize> for Sorted<C> {
+  type Safety = C::Safety;
+  type Complexity = C::Complexity;
+  type Value<'a>
+    = C::Value<'a>
+  where
+    Self: 'a;
+
+  /// Indexes the values in ascending order
+  fn get(&self, k: usize) -> Option<C::Value<'_>> {
+    C::get(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
 impl<V> Get<usize> for Vec<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: usize) -> Option<&'a V> {
+  fn get(&self, k: usize) -> Option<&V> {
     <[V]>::get(self, k)
   }
 }
 
-#[cfg(feature = "std")]
-impl<V> Get<usize> for std::collections::VecDeque<V> {
+#[cfg(feature = "alloc")]
+impl<V> Get<usize> for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: usize) -> Option<&'a V> {
-    std::collections::VecDeque::get(self, k)
+  fn get(&self, k: usize) -> Option<&V> {
+    alloc::collections::VecDeque::get(self, k)
   }
 }
 
-#[cfg(feature = "std")]
-impl<V> Get<usize> for std::collections::LinkedList<V> {
+#[cfg(feature = "alloc")]
+impl<V> Get<usize> for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type Complexity = Linear;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: usize) -> Option<&'a V> {
-    self.iter().skip(k).next()
+  fn get(&self, k: usize) -> Option<&V> {
+    self.iter().nth(k)
   }
 }
 
-#[cfg(feature = "std")]
-impl<'k, K: Ord, V> Get<&'k K> for std::collections::BTreeMap<K, V> {
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord, V> Get<&'k Q> for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Complexity = Logarithmic;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: &'k K) -> Option<&'a V> {
-    std::collections::BTreeMap::get(self, &k)
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a V> {
+    alloc::collections::BTreeMap::get(self, k)
   }
 }
 
-#[cfg(feature = "std")]
-impl<'k, K: Ord> Get<&'k K> for std::collections::BTreeSet<K> {
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord> Get<&'k Q> for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type Complexity = Logarithmic;
   type Value<'a>
+    = &'a K
   where
-    Self: 'a,
-  = &'a K;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: &'k K) -> Option<&'a K> {
-    std::collections::BTreeSet::get(self, &k)
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a K> {
+    alloc::collections::BTreeSet::get(self, k)
   }
 }
 
 #[cfg(feature = "std")]
-impl<'k, K: Eq + Hash, V> Get<&'k K> for std::collections::HashMap<K, V> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Get<&'k Q>
+  for std::collections::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a V
   where
-    Self: 'a,
-  = &'a V;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: &'k K) -> Option<&'a V> {
-    std::collections::HashMap::get(self, &k)
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a V> {
+    std::collections::HashMap::get(self, k)
   }
 }
 
 #[cfg(feature = "std")]
-impl<'k, K: Eq + Hash> Get<&'k K> for std::collections::HashSet<K> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Get<&'k Q>
+  for std::collections::HashSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a K
   where
-    Self: 'a,
-  = &'a K;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: &'k K) -> Option<&'a K> {
-    std::collections::HashSet::get(self, &k)
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a K> {
+    std::collections::HashSet::get(self, k)
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<'k, K: Eq + Hash, V> Get<&'k K> for dashmap::DashMap<K, V> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher + Clone> Get<&'k Q>
+  for dashmap::DashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = dashmap::mapref::one::Ref<'a, K, V, S>
   where
-    Self: 'a,
-  = dashmap::mapref::one::Ref<'a, K, V>;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: &'k K) -> Option<Self::Value<'a>> {
-    dashmap::DashMap::get(self, &k)
+  fn get<'a>(&'a self, k: &'k Q) -> Option<Self::Value<'a>> {
+    dashmap::DashMap::get(self, k)
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<'k, K: Eq + Hash> Get<&'k K> for dashmap::DashSet<K> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher + Clone> Get<&'k Q>
+  for dashmap::DashSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = dashmap::setref::one::Ref<'a, K, S>
   where
-    Self: 'a,
-  = dashmap::setref::one::Ref<'a, K>;
+    Self: 'a;
 
-  fn get<'a>(&'a self, k: &'k K) -> Option<Self::Value<'a>> {
-    dashmap::DashSet::get(self, &k)
+  fn get<'a>(&'a self, k: &'k Q) -> Option<Self::Value<'a>> {
+    dashmap::DashSet::get(self, k)
   }
 }
 
 #[cfg(feature = "serde_json")]
 impl<'k> Get<&'k str> for serde_json::Value {
+  type Safety = Safe;
+  type Complexity = Logarithmic;
   type Value<'a>
+    = &'a serde_json::Value
   where
-    Self: 'a,
-  = &'a serde_json::Value;
+    Self: 'a;
 
   /// Returns `None` if the value is not an object or `k` is missing
   fn get<'a>(&'a self, k: &'k str) -> Option<Self::Value<'a>> {
@@ -213,10 +276,12 @@ impl<'k> Get<&'k str> for serde_json::Value {
 
 #[cfg(feature = "serde_json")]
 impl Get<usize> for serde_json::Value {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a serde_json::Value
   where
-    Self: 'a,
-  = &'a serde_json::Value;
+    Self: 'a;
 
   /// Returns `None` if the value is not an array or `k` is out of bounds
   fn get<'a>(&'a self, k: usize) -> Option<Self::Value<'a>> {
@@ -229,10 +294,12 @@ impl Get<usize> for serde_json::Value {
 
 #[cfg(feature = "simd-json")]
 impl<'k> Get<&'k str> for simd_json::BorrowedValue<'_> {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a simd_json::BorrowedValue<'a>
   where
-    Self: 'a,
-  = &'a simd_json::BorrowedValue<'a>;
+    Self: 'a;
 
   /// Returns `None` if the value is not an object or `k` is missing
   fn get<'a>(&'a self, k: &'k str) -> Option<Self::Value<'a>> {
@@ -245,10 +312,12 @@ impl<'k> Get<&'k str> for simd_json::BorrowedValue<'_> {
 
 #[cfg(feature = "simd-json")]
 impl Get<usize> for simd_json::BorrowedValue<'_> {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a simd_json::BorrowedValue<'a>
   where
-    Self: 'a,
-  = &'a simd_json::BorrowedValue<'a>;
+    Self: 'a;
 
   /// Returns `None` if the value is not an array or `k` is out of bounds
   fn get<'a>(&'a self, k: usize) -> Option<Self::Value<'a>> {
@@ -261,10 +330,12 @@ impl Get<usize> for simd_json::BorrowedValue<'_> {
 
 #[cfg(feature = "simd-json")]
 impl<'k> Get<&'k str> for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a simd_json::OwnedValue
   where
-    Self: 'a,
-  = &'a simd_json::OwnedValue;
+    Self: 'a;
 
   /// Returns `None` if the value is not an object or `k` is missing
   fn get<'a>(&'a self, k: &'k str) -> Option<Self::Value<'a>> {
@@ -277,10 +348,12 @@ impl<'k> Get<&'k str> for simd_json::OwnedValue {
 
 #[cfg(feature = "simd-json")]
 impl Get<usize> for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Complexity = Constant;
   type Value<'a>
+    = &'a simd_json::OwnedValue
   where
-    Self: 'a,
-  = &'a simd_json::OwnedValue;
+    Self: 'a;
 
   /// Returns `None` if the value is not an array or `k` is out of bounds
   fn get<'a>(&'a self, k: usize) -> Option<Self::Value<'a>> {
@@ -291,23 +364,234 @@ impl Get<usize> for simd_json::OwnedValue {
   }
 }
 
-// #[cfg(feature = "slab")]
-// impl<V> Get<usize> for slab::Slab<V> {
-//   type Key = usize;
-//   type Value<'a>
-//   where
-//     Self: 'a,
-//   = &'a V;
+#[cfg(feature = "slab")]
+impl<V> Get<usize> for slab::Slab<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
 
-//   fn get<'a>(&'a self, k: usize) -> Option<Self::Value<'a>> {
-//     slab::Slab::get(self, k)
-//   }
-// }
+  fn get(&self, k: usize) -> Option<&V> {
+    slab::Slab::get(self, k)
+  }
+
+  fn contains(&self, k: usize) -> bool {
+    slab::Slab::contains(self, k)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Get<usize> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn get(&self, k: usize) -> Option<&A::Item> {
+    <[A::Item]>::get(self, k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use crate::Position;
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Get<&'k Q>
+  for indexmap::IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a V> {
+    indexmap::IndexMap::get(self, k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Get<Position> for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = (&'a K, &'a V)
+  where
+    Self: 'a;
+
+  fn get<'a>(&'a self, k: Position) -> Option<Self::Value<'a>> {
+    indexmap::IndexMap::get_index(self, k.0)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Get<&'k Q>
+  for indexmap::IndexSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a K> {
+    indexmap::IndexSet::get(self, k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Get<Position> for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn get(&self, k: Position) -> Option<&K> {
+    indexmap::IndexSet::get_index(self, k.0)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Get<&'k Q>
+  for hashbrown::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a V> {
+    hashbrown::HashMap::get(self, k)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Get<&'k Q>
+  for hashbrown::HashSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a K> {
+    hashbrown::HashSet::get(self, k)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Get<usize> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn get(&self, k: usize) -> Option<&V> {
+    <[V]>::get(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Get<usize> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn get(&self, k: usize) -> Option<&V> {
+    <[V]>::get(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Get<usize> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn get(&self, k: usize) -> Option<&V> {
+    let (front, back) = heapless::Deque::as_slices(self);
+    match k.checked_sub(front.len()) {
+      Some(k) => back.get(k),
+      None => front.get(k),
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher, const N: usize>
+  Get<&'k Q> for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn get<'a>(&'a self, k: &'k Q) -> Option<&'a V> {
+    heapless::IndexMap::get(self, k)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Get<usize> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn get(&self, k: usize) -> Option<&A::Item> {
+    <[A::Item]>::get(self, k)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Get<usize> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn get(&self, k: usize) -> Option<&A::Item> {
+    <[A::Item]>::get(self, k)
+  }
+}
 
 #[cfg(test)]
+#[cfg(feature = "std")]
 mod tests {
   use super::*;
   #[test]
+  fn sorted() {
+    let s = Sorted::new(vec![2, 0, 1]);
+    assert_eq!(<Sorted<Vec<i32>> as Get<usize>>::get(&s, 0), Some(&0));
+    assert_eq!(<Sorted<Vec<i32>> as Get<usize>>::get(&s, 3), None);
+  }
+  #[test]
   fn array() {
     assert_eq!(<[i32] as Get<usize>>::get(&[], 1), None);
     assert_eq!(<[i32] as Get<usize>>::get(&[0, 1], 1), Some(&1));
@@ -321,28 +605,28 @@ mod tests {
   #[test]
   fn vec_deque() {
     assert_eq!(
-      <std::collections::VecDeque<()> as Get<_>>::get(&Default::default(), 0),
+      <alloc::collections::VecDeque<()> as Get<_>>::get(&Default::default(), 0),
       None
     );
   }
   #[test]
   fn linked_list() {
     assert_eq!(
-      <std::collections::LinkedList<()> as Get<_>>::get(&Default::default(), 0),
+      <alloc::collections::LinkedList<()> as Get<_>>::get(&Default::default(), 0),
       None
     );
   }
   #[test]
   fn b_tree_map() {
     assert_eq!(
-      <std::collections::BTreeMap<(), ()> as Get<_>>::get(&Default::default(), &()),
+      <alloc::collections::BTreeMap<(), ()> as Get<_>>::get(&Default::default(), &()),
       None
     );
   }
   #[test]
   fn b_tree_set() {
     assert_eq!(
-      <std::collections::BTreeSet<()> as Get<_>>::get(&Default::default(), &()),
+      <alloc::collections::BTreeSet<()> as Get<_>>::get(&Default::default(), &()),
       None
     );
   }
@@ -354,12 +638,33 @@ mod tests {
     );
   }
   #[test]
+  fn borrowed_key() {
+    let m = std::collections::HashMap::from([(String::from("A"), 1)]);
+    assert_eq!(
+      <std::collections::HashMap<String, i32> as Get<&str>>::get(&m, "A"),
+      Some(&1)
+    );
+    let s = alloc::collections::BTreeSet::from([String::from("A")]);
+    assert!(<alloc::collections::BTreeSet<String> as Get<&str>>::contains(&s, "A"));
+    assert!(!<alloc::collections::BTreeSet<String> as Get<&str>>::contains(&s, "B"));
+  }
+  #[test]
   fn hash_set() {
     assert_eq!(
       <std::collections::HashSet<()> as Get<_>>::get(&Default::default(), &()),
       None
     );
   }
+  #[test]
+  fn custom_hasher() {
+    type Hasher = std::hash::BuildHasherDefault<std::hash::DefaultHasher>;
+    let mut m = std::collections::HashMap::<String, i32, Hasher>::default();
+    m.insert(String::from("A"), 1);
+    assert_eq!(
+      <std::collections::HashMap<String, i32, Hasher> as Get<&str>>::get(&m, "A"),
+      Some(&1)
+    );
+  }
 }
 
 #[cfg(test)]
@@ -423,28 +728,153 @@ mod simd_json_tests {
   }
 }
 
-// #[cfg(test)]
-// #[cfg(feature = "slab")]
-// mod slab_tests {
-//   use super::*;
-//   #[test]
-//   fn slab() {
-//     assert_eq!(
-//       <slab::Slab<()> as Get<_>>::get(&slab::Slab::<()>::new(), 0),
-//       None
-//     );
-//   }
-// }
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    assert_eq!(
+      <slab::Slab<()> as Get<_>>::get(&slab::Slab::<()>::new(), 0),
+      None
+    );
+    let mut s = slab::Slab::new();
+    let k = s.insert(1);
+    s.insert(2);
+    s.remove(k);
+    assert!(!<slab::Slab<i32> as Get<_>>::contains(&s, k));
+    assert_eq!(<slab::Slab<i32> as Get<&usize>>::get(&s, &1), Some(&2));
+  }
+}
 
-// #[cfg(test)]
-// #[cfg(feature = "smallvec")]
-// mod smallvec_tests {
-//   use super::*;
-//   #[test]
-//   fn small_vec() {
-//     assert_eq!(
-//       <[()] as Get<_>>::get(&smallvec::SmallVec::<[(); 0]>::new(), 0),
-//       None
-//     );
-//   }
-// }
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn small_vec() {
+    assert_eq!(
+      <smallvec::SmallVec<[(); 0]> as Get<_>>::get(&smallvec::SmallVec::<[(); 0]>::new(), 0),
+      None
+    );
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 1]> as Get<_>>::get(&smallvec::smallvec![0, 1], 1),
+      Some(&1)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn index_map() {
+    let m = indexmap::IndexMap::from([(String::from("B"), 2), (String::from("A"), 1)]);
+    assert_eq!(
+      <indexmap::IndexMap<String, i32> as Get<&str>>::get(&m, "A"),
+      Some(&1)
+    );
+    assert!(!<indexmap::IndexMap<String, i32> as Get<&str>>::contains(
+      &m, "C"
+    ));
+    assert_eq!(
+      <indexmap::IndexMap<String, i32> as Get<Position>>::get(&m, Position(0)),
+      Some((&String::from("B"), &2))
+    );
+    assert_eq!(
+      <indexmap::IndexMap<String, i32> as Get<Position>>::get(&m, Position(2)),
+      None
+    );
+  }
+  #[test]
+  fn index_set() {
+    let s = indexmap::IndexSet::from([2, 1]);
+    assert_eq!(<indexmap::IndexSet<i32> as Get<_>>::get(&s, &1), Some(&1));
+    assert_eq!(
+      <indexmap::IndexSet<i32> as Get<Position>>::get(&s, Position(1)),
+      Some(&1)
+    );
+    assert!(!<indexmap::IndexSet<i32> as Get<Position>>::contains(
+      &s,
+      Position(2)
+    ));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let m = hashbrown::HashMap::from([(String::from("A"), 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<String, i32> as Get<&str>>::get(&m, "A"),
+      Some(&1)
+    );
+    assert!(!<hashbrown::HashMap<String, i32> as Get<&str>>::contains(
+      &m, "B"
+    ));
+    let s = hashbrown::HashSet::from([String::from("A")]);
+    assert_eq!(
+      <hashbrown::HashSet<String> as Get<&str>>::get(&s, "A").map(String::as_str),
+      Some("A")
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let v = arrayvec::ArrayVec::<i32, 4>::from_iter([1, 2]);
+    assert_eq!(
+      <arrayvec::ArrayVec<i32, 4> as Get<usize>>::get(&v, 1),
+      Some(&2)
+    );
+    assert_eq!(<arrayvec::ArrayVec<i32, 4> as Get<usize>>::get(&v, 2), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut d = heapless::Deque::<i32, 4>::new();
+    d.push_back(2).unwrap();
+    d.push_back(3).unwrap();
+    d.push_front(1).unwrap();
+    assert_eq!(
+      (0..4)
+        .map(|k| <heapless::Deque<i32, 4> as Get<usize>>::get(&d, k))
+        .collect::<Vec<_>>(),
+      [Some(&1), Some(&2), Some(&3), None]
+    );
+    let m = heapless::FnvIndexMap::<&str, i32, 4>::from_iter([("a", 1)]);
+    assert_eq!(Get::get(&m, "a"), Some(&1));
+    assert_eq!(Get::get(&m, "b"), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let v = tinyvec::ArrayVec::<[i32; 4]>::from_iter([1, 2]);
+    assert_eq!(
+      <tinyvec::ArrayVec<[i32; 4]> as Get<usize>>::get(&v, 1),
+      Some(&2)
+    );
+    assert_eq!(
+      <tinyvec::ArrayVec<[i32; 4]> as Get<usize>>::get(&v, 2),
+      None
+    );
+  }
+}
@@ -0,0 +1,621 @@
+use crate::{Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use core::borrow::Borrow;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides mutable access to a value at a specified key.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::GetMut;
+///
+/// fn increment<K>(
+///   col: &mut impl for<'a> GetMut<K, ValueMut<'a> = &'a mut i32>,
+///   pos: K,
+/// ) {
+///   if let Some(v) = col.get_mut(pos) {
+///     *v += 1;
+///   }
+/// }
+///
+/// let mut v = vec![0];
+/// increment(&mut v, 0);
+/// assert_eq!(v, vec![1]);
+/// let mut m = HashMap::from([("A", 1)]);
+/// increment(&mut m, &"A");
+/// assert_eq!(m["A"], 2);
+/// # }
+/// ```
+pub trait GetMut<K> {
+  /// Indicates whether the `get_mut` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the value returned by `get_mut`.
+  type ValueMut<'a>
+  where
+    Self: 'a;
+  /// Returns the `Option`-wrapped mutable value, or `None` if `k` is missing. The returned value can be either a mutable reference or a guard, depending on the implementer.
+  fn get_mut<'a>(&'a mut self, k: K) -> Option<Self::ValueMut<'a>>;
+}
+
+impl<'k, G: GetMut<usize>> GetMut<&'k usize> for G {
+  type Safety = <G as GetMut<usize>>::Safety;
+  type ValueMut<'a>
+    = <G as GetMut<usize>>::ValueMut<'a>
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k usize) -> Option<Self::ValueMut<'a>> {
+    <G as GetMut<usize>>::get_mut(self, *k)
+  }
+}
+
+impl<V> GetMut<usize> for [V] {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    <[V]>::get_mut(self, k)
+  }
+}
+
+impl<V, const N: usize> GetMut<usize> for [V; N] {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    <[V]>::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> GetMut<usize> for Vec<V> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    <[V]>::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> GetMut<usize> for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    alloc::collections::VecDeque::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> GetMut<usize> for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    self.iter_mut().nth(k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord, V> GetMut<&'k Q>
+  for alloc::collections::BTreeMap<K, V>
+{
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k Q) -> Option<&'a mut V> {
+    alloc::collections::BTreeMap::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> GetMut<&'k Q>
+  for std::collections::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k Q) -> Option<&'a mut V> {
+    std::collections::HashMap::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher + Clone> GetMut<&'k Q>
+  for dashmap::DashMap<K, V, S>
+{
+  type Safety = Safe;
+  type ValueMut<'a>
+    = dashmap::mapref::one::RefMut<'a, K, V, S>
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k Q) -> Option<Self::ValueMut<'a>> {
+    dashmap::DashMap::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl<'k> GetMut<&'k str> for serde_json::Value {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut serde_json::Value
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an object or `k` is missing
+  fn get_mut<'a>(&'a mut self, k: &'k str) -> Option<Self::ValueMut<'a>> {
+    match self {
+      serde_json::Value::Object(o) => o.get_mut(k),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl GetMut<usize> for serde_json::Value {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut serde_json::Value
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an array or `k` is out of bounds
+  fn get_mut<'a>(&'a mut self, k: usize) -> Option<Self::ValueMut<'a>> {
+    match self {
+      serde_json::Value::Array(a) => a.get_mut(k),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'k, 'v> GetMut<&'k str> for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut simd_json::BorrowedValue<'v>
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an object or `k` is missing
+  fn get_mut<'a>(&'a mut self, k: &'k str) -> Option<Self::ValueMut<'a>> {
+    match self {
+      simd_json::BorrowedValue::Object(o) => o.get_mut(k),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> GetMut<usize> for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut simd_json::BorrowedValue<'v>
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an array or `k` is out of bounds
+  fn get_mut<'a>(&'a mut self, k: usize) -> Option<Self::ValueMut<'a>> {
+    match self {
+      simd_json::BorrowedValue::Array(a) => a.get_mut(k),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'k> GetMut<&'k str> for simd_json::OwnedValue {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut simd_json::OwnedValue
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an object or `k` is missing
+  fn get_mut<'a>(&'a mut self, k: &'k str) -> Option<Self::ValueMut<'a>> {
+    match self {
+      simd_json::OwnedValue::Object(o) => o.get_mut(k),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl GetMut<usize> for simd_json::OwnedValue {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut simd_json::OwnedValue
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an array or `k` is out of bounds
+  fn get_mut<'a>(&'a mut self, k: usize) -> Option<Self::ValueMut<'a>> {
+    match self {
+      simd_json::OwnedValue::Array(a) => a.get_mut(k),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> GetMut<usize> for slab::Slab<V> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    slab::Slab::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> GetMut<usize> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut A::Item
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut A::Item> {
+    <[A::Item]>::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> GetMut<&'k Q>
+  for indexmap::IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k Q) -> Option<&'a mut V> {
+    indexmap::IndexMap::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> GetMut<crate::Position> for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = (&'a K, &'a mut V)
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: crate::Position) -> Option<Self::ValueMut<'a>> {
+    indexmap::IndexMap::get_index_mut(self, k.0)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> GetMut<&'k Q>
+  for hashbrown::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k Q) -> Option<&'a mut V> {
+    hashbrown::HashMap::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> GetMut<usize> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    <[V]>::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> GetMut<usize> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    <[V]>::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> GetMut<usize> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut V> {
+    let (front, back) = heapless::Deque::as_mut_slices(self);
+    match k.checked_sub(front.len()) {
+      Some(k) => back.get_mut(k),
+      None => front.get_mut(k),
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher, const N: usize>
+  GetMut<&'k Q> for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut V
+  where
+    Self: 'a;
+
+  fn get_mut<'a>(&'a mut self, k: &'k Q) -> Option<&'a mut V> {
+    heapless::IndexMap::get_mut(self, k)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> GetMut<usize> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut A::Item
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut A::Item> {
+    <[A::Item]>::get_mut(self, k)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> GetMut<usize> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type ValueMut<'a>
+    = &'a mut A::Item
+  where
+    Self: 'a;
+
+  fn get_mut(&mut self, k: usize) -> Option<&mut A::Item> {
+    <[A::Item]>::get_mut(self, k)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn array() {
+    assert_eq!(<[i32] as GetMut<usize>>::get_mut(&mut [], 1), None);
+    assert_eq!(
+      <[i32] as GetMut<usize>>::get_mut(&mut [0, 1], 1),
+      Some(&mut 1)
+    );
+    assert_eq!(<[usize; 0] as GetMut<usize>>::get_mut(&mut [0; 0], 0), None);
+    assert_eq!(
+      <[usize; 1] as GetMut<&usize>>::get_mut(&mut [0; 1], &0),
+      Some(&mut 0)
+    );
+  }
+  #[test]
+  fn vec() {
+    let mut v = vec![10];
+    *<Vec<i32> as GetMut<_>>::get_mut(&mut v, 0).unwrap() = 11;
+    assert_eq!(v, vec![11]);
+  }
+  #[test]
+  fn vec_deque() {
+    assert_eq!(
+      <alloc::collections::VecDeque<()> as GetMut<_>>::get_mut(&mut Default::default(), 0),
+      None
+    );
+  }
+  #[test]
+  fn linked_list() {
+    let mut l = alloc::collections::LinkedList::from([0, 1, 2]);
+    *<alloc::collections::LinkedList<i32> as GetMut<_>>::get_mut(&mut l, 1).unwrap() = 3;
+    assert_eq!(l, alloc::collections::LinkedList::from([0, 3, 2]));
+  }
+  #[test]
+  fn b_tree_map() {
+    assert_eq!(
+      <alloc::collections::BTreeMap<(), ()> as GetMut<_>>::get_mut(&mut Default::default(), &()),
+      None
+    );
+  }
+  #[test]
+  fn hash_map() {
+    let mut m = std::collections::HashMap::from([(0, 0)]);
+    *<std::collections::HashMap<i32, i32> as GetMut<_>>::get_mut(&mut m, &0).unwrap() = 1;
+    assert_eq!(m[&0], 1);
+  }
+  #[test]
+  fn borrowed_key() {
+    let mut m = alloc::collections::BTreeMap::from([(String::from("A"), 0)]);
+    *<alloc::collections::BTreeMap<String, i32> as GetMut<&str>>::get_mut(&mut m, "A").unwrap() = 1;
+    assert_eq!(m["A"], 1);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dash_map() {
+    let mut m = dashmap::DashMap::new();
+    m.insert(0, 0);
+    *<dashmap::DashMap<i32, i32> as GetMut<_>>::get_mut(&mut m, &0).unwrap() = 1;
+    assert_eq!(m.get(&0).map(|v| *v), Some(1));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json_object() {
+    assert_eq!(
+      <serde_json::Value as GetMut<&str>>::get_mut(&mut serde_json::Value::Null, ""),
+      None
+    );
+  }
+  #[test]
+  fn serde_json_array() {
+    let mut a = serde_json::Value::Array(vec![serde_json::Value::Bool(true)]);
+    *<serde_json::Value as GetMut<usize>>::get_mut(&mut a, 0).unwrap() = serde_json::Value::Null;
+    assert_eq!(a, serde_json::Value::Array(vec![serde_json::Value::Null]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  #[test]
+  fn simd_json_borrowed_object() {
+    assert_eq!(
+      <simd_json::BorrowedValue as GetMut<&str>>::get_mut(
+        &mut simd_json::BorrowedValue::Static(simd_json::StaticNode::Null),
+        ""
+      ),
+      None
+    );
+  }
+  #[test]
+  fn simd_json_borrowed_array() {
+    assert_eq!(
+      <simd_json::BorrowedValue as GetMut<usize>>::get_mut(
+        &mut simd_json::BorrowedValue::Array(vec![]),
+        0
+      ),
+      None
+    );
+  }
+  #[test]
+  fn simd_json_owned_object() {
+    assert_eq!(
+      <simd_json::OwnedValue as GetMut<&str>>::get_mut(
+        &mut simd_json::OwnedValue::Static(simd_json::StaticNode::Null),
+        ""
+      ),
+      None
+    );
+  }
+  #[test]
+  fn simd_json_owned_array() {
+    let mut a = simd_json::OwnedValue::Array(vec![simd_json::OwnedValue::Static(
+      simd_json::StaticNode::Null,
+    )]);
+    assert_eq!(
+      <simd_json::OwnedValue as GetMut<usize>>::get_mut(&mut a, 0),
+      Some(&mut simd_json::OwnedValue::Static(
+        simd_json::StaticNode::Null
+      ))
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    let k = s.insert(0);
+    *<slab::Slab<i32> as GetMut<_>>::get_mut(&mut s, k).unwrap() = 1;
+    assert_eq!(s[k], 1);
+    s.remove(k);
+    assert_eq!(<slab::Slab<i32> as GetMut<_>>::get_mut(&mut s, k), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn small_vec() {
+    let mut v: smallvec::SmallVec<[i32; 1]> = smallvec::smallvec![0, 1];
+    *<smallvec::SmallVec<[i32; 1]> as GetMut<_>>::get_mut(&mut v, 1).unwrap() = 2;
+    assert_eq!(&v[..], &[0, 2]);
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 1]> as GetMut<_>>::get_mut(&mut v, 2),
+      None
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  use crate::Position;
+  #[test]
+  fn indexmap() {
+    let mut m = indexmap::IndexMap::from([(String::from("B"), 0), (String::from("A"), 0)]);
+    *<indexmap::IndexMap<String, i32> as GetMut<&str>>::get_mut(&mut m, "A").unwrap() = 1;
+    *<indexmap::IndexMap<String, i32> as GetMut<Position>>::get_mut(&mut m, Position(0))
+      .unwrap()
+      .1 = 2;
+    assert_eq!(m["A"], 1);
+    assert_eq!(m["B"], 2);
+    assert_eq!(
+      <indexmap::IndexMap<String, i32> as GetMut<Position>>::get_mut(&mut m, Position(2)),
+      None
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(String::from("A"), 1)]);
+    if let Some(v) = <hashbrown::HashMap<String, i32> as GetMut<&str>>::get_mut(&mut m, "A") {
+      *v = 2;
+    }
+    assert_eq!(m["A"], 2);
+  }
+}
@@ -1,14 +1,30 @@
-use crate::{Safe, SafetyMarker, Unsafe};
-#[cfg(feature = "std")]
-use std::{
-  collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
-  hash::Hash,
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+use crate::AmortizedConstant;
+use crate::{ComplexityMarker, Constant, KeyStabilityMarker, SafetyMarker, Stable, Unsafe};
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::{Linear, Shifting};
+#[cfg(feature = "alloc")]
+use crate::{Logarithmic, Safe};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  vec::Vec,
 };
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// Provides the ability to insert a provided value at a specified index, possibly overwriting the previous value.
 ///
 /// ## Examples
 /// ```
+/// # #[cfg(feature = "alloc")] {
 /// use std::borrow::Cow;
 /// use collectivity::Insert;
 ///
@@ -28,10 +44,15 @@ use std::{
 /// let mut v = [0, 1];
 /// insert(&mut v, 0, 1);
 /// assert_eq!(v[0], 1);
+/// # }
 /// ```
 pub trait Insert<K, V> {
   /// Indicates whether the `insert` method may panic in a particular implementation.
   type Safety: SafetyMarker;
+  /// Indicates the time complexity of the `insert` method in a particular implementation.
+  type Complexity: ComplexityMarker;
+  /// Indicates whether the `insert` method may change the keys of other entries in a particular implementation.
+  type KeyStability: KeyStabilityMarker;
   /// Inserts value `v` at key `k`.
   ///
   /// # Panics
@@ -40,15 +61,19 @@ pub trait Insert<K, V> {
   fn insert(&mut self, k: K, v: V);
 }
 
-impl<'i, K, V, I: Insert<K, V>> Insert<K, V> for &'i mut I {
+impl<K, V, I: Insert<K, V>> Insert<K, V> for &mut I {
   type Safety = <I as Insert<K, V>>::Safety;
-  fn insert<'a>(&'a mut self, k: K, v: V) {
+  type Complexity = <I as Insert<K, V>>::Complexity;
+  type KeyStability = <I as Insert<K, V>>::KeyStability;
+  fn insert(&mut self, k: K, v: V) {
     <I as Insert<K, V>>::insert(self, k, v)
   }
 }
 
 impl<V, const N: usize> Insert<usize, V> for [V; N] {
   type Safety = Unsafe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: usize, v: V) {
     self[k] = v
   }
@@ -56,29 +81,38 @@ impl<V, const N: usize> Insert<usize, V> for [V; N] {
 
 impl<V> Insert<usize, V> for [V] {
   type Safety = Unsafe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: usize, v: V) {
     self[k] = v
   }
 }
 
+#[cfg(feature = "alloc")]
 impl<V> Insert<usize, V> for Vec<V> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: V) {
     self.insert(k, v)
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> Insert<usize, V> for VecDeque<V> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: V) {
     self.insert(k, v)
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> Insert<usize, V> for LinkedList<V> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: V) {
     let mut rest = self.split_off(k);
     self.push_back(v);
@@ -86,33 +120,41 @@ impl<V> Insert<usize, V> for LinkedList<V> {
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<K: Ord, V> Insert<K, V> for BTreeMap<K, V> {
   type Safety = Safe;
+  type Complexity = Logarithmic;
+  type KeyStability = Stable;
   fn insert(&mut self, k: K, v: V) {
     self.insert(k, v);
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<K: Ord> Insert<K, ()> for BTreeSet<K> {
   type Safety = Safe;
+  type Complexity = Logarithmic;
+  type KeyStability = Stable;
   fn insert(&mut self, k: K, _v: ()) {
     self.insert(k);
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash, V> Insert<K, V> for HashMap<K, V> {
+impl<K: Eq + Hash, V, S: BuildHasher> Insert<K, V> for HashMap<K, V, S> {
   type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: K, v: V) {
     self.insert(k, v);
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash> Insert<K, ()> for HashSet<K> {
+impl<K: Eq + Hash, S: BuildHasher> Insert<K, ()> for HashSet<K, S> {
   type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: K, _v: ()) {
     self.insert(k);
   }
@@ -122,16 +164,20 @@ impl<K: Eq + Hash> Insert<K, ()> for HashSet<K> {
 use dashmap::{DashMap, DashSet};
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash, V> Insert<K, V> for DashMap<K, V> {
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Insert<K, V> for DashMap<K, V, S> {
   type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: K, v: V) {
     DashMap::insert(self, k, v);
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash> Insert<K, ()> for DashSet<K> {
+impl<K: Eq + Hash, S: BuildHasher + Clone> Insert<K, ()> for DashSet<K, S> {
   type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: K, _v: ()) {
     DashSet::insert(self, k);
   }
@@ -143,6 +189,8 @@ use serde_json::Value as SeV;
 #[cfg(feature = "serde_json")]
 impl Insert<usize, SeV> for SeV {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: SeV) {
     match self {
       SeV::Array(a) => {
@@ -156,6 +204,8 @@ impl Insert<usize, SeV> for SeV {
 #[cfg(feature = "serde_json")]
 impl Insert<String, SeV> for SeV {
   type Safety = Unsafe;
+  type Complexity = Logarithmic;
+  type KeyStability = Stable;
   fn insert(&mut self, k: String, v: SeV) {
     match self {
       SeV::Object(o) => {
@@ -172,6 +222,8 @@ use simd_json::{cow::Cow, BorrowedValue as SBV, OwnedValue as SOV};
 #[cfg(feature = "simd-json")]
 impl<'a> Insert<usize, SBV<'a>> for SBV<'a> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: SBV<'a>) {
     match self {
       SBV::Array(a) => {
@@ -185,6 +237,8 @@ impl<'a> Insert<usize, SBV<'a>> for SBV<'a> {
 #[cfg(feature = "simd-json")]
 impl<'a> Insert<Cow<'a, str>, SBV<'a>> for SBV<'a> {
   type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: Cow<'a, str>, v: SBV<'a>) {
     match self {
       SBV::Object(o) => {
@@ -198,6 +252,8 @@ impl<'a> Insert<Cow<'a, str>, SBV<'a>> for SBV<'a> {
 #[cfg(feature = "simd-json")]
 impl Insert<usize, SOV> for SOV {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: SOV) {
     match self {
       SOV::Array(a) => {
@@ -210,7 +266,9 @@ impl Insert<usize, SOV> for SOV {
 
 #[cfg(feature = "simd-json")]
 impl Insert<String, SOV> for SOV {
-  type Safety = Safe;
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
   fn insert(&mut self, k: String, v: SOV) {
     match self {
       SOV::Object(o) => {
@@ -227,12 +285,120 @@ use smallvec::{Array, SmallVec};
 #[cfg(feature = "smallvec")]
 impl<V, A: Array<Item = V>> Insert<usize, V> for SmallVec<A> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn insert(&mut self, k: usize, v: V) {
     self.insert(k, v)
   }
 }
 
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Insert<K, V> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
+  /// Appends a new key, or replaces the value of an existing key in place
+  fn insert(&mut self, k: K, v: V) {
+    self.insert(k, v);
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Insert<K, ()> for IndexSet<K, S> {
+  type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
+  /// Appends a new value, leaving an existing one in place
+  fn insert(&mut self, k: K, _v: ()) {
+    self.insert(k);
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Insert<K, V> for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
+  fn insert(&mut self, k: K, v: V) {
+    hashbrown::HashMap::insert(self, k, v);
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Insert<K, ()> for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  type KeyStability = Stable;
+  fn insert(&mut self, k: K, _v: ()) {
+    hashbrown::HashSet::insert(self, k);
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Insert<usize, V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  /// Panics if the collection is full
+  fn insert(&mut self, k: usize, v: V) {
+    arrayvec::ArrayVec::insert(self, k, v)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Insert<usize, V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  /// Panics if the collection is full
+  fn insert(&mut self, k: usize, v: V) {
+    if heapless::Vec::insert(self, k, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Insert<K, V>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Unsafe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  /// Panics if the key is new and the map is full
+  fn insert(&mut self, k: K, v: V) {
+    if heapless::IndexMap::insert(self, k, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Insert<usize, A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  /// Panics if the collection is full
+  fn insert(&mut self, k: usize, v: A::Item) {
+    tinyvec::ArrayVec::insert(self, k, v)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Insert<usize, A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  fn insert(&mut self, k: usize, v: A::Item) {
+    tinyvec::TinyVec::insert(self, k, v)
+  }
+}
+
 #[cfg(test)]
+#[cfg(feature = "std")]
 mod tests {
   use super::*;
   use crate::Get;
@@ -270,6 +436,16 @@ mod tests {
     <HashSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
     assert_eq!(s.get(&0), Some(&0));
   }
+  #[test]
+  fn custom_hasher() {
+    type Hasher = std::hash::BuildHasherDefault<std::hash::DefaultHasher>;
+    let mut m = HashMap::<i32, i32, Hasher>::default();
+    <HashMap<i32, i32, Hasher> as Insert<i32, i32>>::insert(&mut m, 0, 1);
+    assert_eq!(m[&0], 1);
+    let mut s = HashSet::<i32, Hasher>::default();
+    <HashSet<i32, Hasher> as Insert<i32, ()>>::insert(&mut s, 0, ());
+    assert_eq!(s.get(&0), Some(&0));
+  }
 }
 
 #[cfg(test)]
@@ -280,10 +456,10 @@ mod dashmap_tests {
   fn dashmap() {
     let mut m = DashMap::new();
     <DashMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 0, 1);
-    assert_eq!(m.get(&0).map(|v| v.clone()), Some(1));
+    assert_eq!(m.get(&0).map(|v| *v), Some(1));
     let mut s = DashSet::new();
     <DashSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
-    assert_eq!(s.get(&0).map(|v| v.clone()), Some(0));
+    assert_eq!(s.get(&0).map(|v| *v), Some(0));
   }
 }
 
@@ -352,3 +528,82 @@ mod smallvec_tests {
     assert_eq!(sv[0], 1);
   }
 }
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::new();
+    <IndexMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 1, 0);
+    <IndexMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 0, 0);
+    <IndexMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 1, 2);
+    assert_eq!(m.iter().collect::<Vec<_>>(), [(&1, &2), (&0, &0)]);
+    let mut s = IndexSet::new();
+    <IndexSet<i32> as Insert<i32, ()>>::insert(&mut s, 1, ());
+    <IndexSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&1, &0]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::new();
+    <hashbrown::HashMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 0, 1);
+    assert_eq!(m, hashbrown::HashMap::from([(0, 1)]));
+    let mut s = hashbrown::HashSet::new();
+    <hashbrown::HashSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
+    assert_eq!(s, hashbrown::HashSet::from([0]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 2>::from_iter([2]);
+    <arrayvec::ArrayVec<i32, 2> as Insert<usize, i32>>::insert(&mut v, 0, 1);
+    assert_eq!(v.as_slice(), &[1, 2]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut v = heapless::Vec::<i32, 2>::from_slice(&[2]).unwrap();
+    <heapless::Vec<i32, 2> as Insert<usize, i32>>::insert(&mut v, 0, 1);
+    assert_eq!(v.as_slice(), &[1, 2]);
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::new();
+    Insert::insert(&mut m, 1, 1);
+    Insert::insert(&mut m, 1, 2);
+    assert_eq!(m.get(&1), Some(&2));
+  }
+  #[test]
+  #[should_panic]
+  fn heapless_full() {
+    let mut v = heapless::Vec::<i32, 1>::from_slice(&[2]).unwrap();
+    <heapless::Vec<i32, 1> as Insert<usize, i32>>::insert(&mut v, 0, 1);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 2]>::from_iter([2]);
+    <tinyvec::ArrayVec<[i32; 2]> as Insert<usize, i32>>::insert(&mut v, 0, 1);
+    assert_eq!(v.as_slice(), &[1, 2]);
+  }
+}
@@ -0,0 +1,559 @@
+use crate::{Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to iterate over the entries of a collection by value, consuming it.
+///
+/// Maps yield key-value pairs, while other collections yield their values. JSON values yield the items of arrays and the values of objects.
+/// Since the method shares its name with [`IntoIterator::into_iter`], calls on collections implementing both traits need to name the trait explicitly.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeSet;
+/// use collectivity::IntoIter;
+///
+/// fn total(col: impl IntoIter<IntoIter = impl Iterator<Item = i32>>) -> i32 {
+///   IntoIter::into_iter(col).sum()
+/// }
+///
+/// assert_eq!(total(vec![1, 2, 3]), 6);
+/// assert_eq!(total(BTreeSet::from([1, 2, 3])), 6);
+/// # }
+/// ```
+pub trait IntoIter {
+  /// Indicates whether the `into_iter` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `into_iter`.
+  type IntoIter: Iterator;
+  /// Consumes the collection and returns an iterator over its entries.
+  fn into_iter(self) -> Self::IntoIter;
+}
+
+impl<V, const N: usize> IntoIter for [V; N] {
+  type Safety = Safe;
+  type IntoIter = core::array::IntoIter<V, N>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+impl<C: IntoIter> IntoIter for Sorted<C> {
+  type Safety = C::Safety;
+  type IntoIter = C::IntoIter;
+
+  /// Yields the values in ascending order
+  fn into_iter(self) -> Self::IntoIter {
+    C::into_iter(self.into_inner())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IntoIter for Vec<V> {
+  type Safety = Safe;
+  type IntoIter = alloc::vec::IntoIter<V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IntoIter for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type IntoIter = alloc::collections::vec_deque::IntoIter<V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IntoIter for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type IntoIter = alloc::collections::linked_list::IntoIter<V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IntoIter for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  type IntoIter = alloc::collections::binary_heap::IntoIter<V>;
+
+  /// Iterates in arbitrary order
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> IntoIter for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type IntoIter = alloc::collections::btree_map::IntoIter<K, V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> IntoIter for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type IntoIter = alloc::collections::btree_set::IntoIter<K>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> IntoIter for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type IntoIter = std::collections::hash_map::IntoIter<K, V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> IntoIter for std::collections::HashSet<K, S> {
+  type Safety = Safe;
+  type IntoIter = std::collections::hash_set::IntoIter<K>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> IntoIter for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  type IntoIter = dashmap::iter::OwningIter<K, V, S>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> IntoIter for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  type IntoIter = dashmap::iter_set::OwningIter<K, S>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl IntoIter for serde_json::Value {
+  type Safety = Safe;
+  type IntoIter = crate::JsonIter<
+    alloc::vec::IntoIter<serde_json::Value>,
+    core::iter::Map<
+      serde_json::map::IntoIter,
+      fn((String, serde_json::Value)) -> serde_json::Value,
+    >,
+  >;
+
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      serde_json::Value::Array(a) => crate::JsonIter::Array(IntoIterator::into_iter(a)),
+      serde_json::Value::Object(o) => {
+        crate::JsonIter::Object(IntoIterator::into_iter(o).map(|(_, v)| v))
+      }
+      _ => crate::JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> IntoIter for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type IntoIter = crate::JsonIter<
+    alloc::vec::IntoIter<simd_json::BorrowedValue<'v>>,
+    core::iter::Map<
+      halfbrown::IntoIter<simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>>,
+      fn(
+        (simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>),
+      ) -> simd_json::BorrowedValue<'v>,
+    >,
+  >;
+
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      simd_json::BorrowedValue::Array(a) => crate::JsonIter::Array(IntoIterator::into_iter(a)),
+      simd_json::BorrowedValue::Object(o) => {
+        crate::JsonIter::Object(IntoIterator::into_iter(*o).map(|(_, v)| v))
+      }
+      _ => crate::JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl IntoIter for simd_json::OwnedValue {
+  type Safety = Safe;
+  type IntoIter = crate::JsonIter<
+    alloc::vec::IntoIter<simd_json::OwnedValue>,
+    core::iter::Map<
+      halfbrown::IntoIter<String, simd_json::OwnedValue>,
+      fn((String, simd_json::OwnedValue)) -> simd_json::OwnedValue,
+    >,
+  >;
+
+  fn into_iter(self) -> Self::IntoIter {
+    match self {
+      simd_json::OwnedValue::Array(a) => crate::JsonIter::Array(IntoIterator::into_iter(a)),
+      simd_json::OwnedValue::Object(o) => {
+        crate::JsonIter::Object(IntoIterator::into_iter(*o).map(|(_, v)| v))
+      }
+      _ => crate::JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> IntoIter for slab::Slab<V> {
+  type Safety = Safe;
+  type IntoIter = slab::IntoIter<V>;
+
+  /// Yields key-value pairs, since `Slab` keys are not contiguous
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> IntoIter for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type IntoIter = smallvec::IntoIter<A>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> IntoIter for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type IntoIter = indexmap::map::IntoIter<K, V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> IntoIter for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  type IntoIter = indexmap::set::IntoIter<K>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> IntoIter for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type IntoIter = hashbrown::hash_map::IntoIter<K, V>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> IntoIter for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  type IntoIter = hashbrown::hash_set::IntoIter<K>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> IntoIter for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type IntoIter = arrayvec::IntoIter<V, CAP>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> IntoIter for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type IntoIter = <heapless::Vec<V, N> as IntoIterator>::IntoIter;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> IntoIter for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type IntoIter = <heapless::Deque<V, N> as IntoIterator>::IntoIter;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> IntoIter
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  type IntoIter = <heapless::Vec<V, N> as IntoIterator>::IntoIter;
+
+  /// Iterates in arbitrary order
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self.into_vec())
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> IntoIter for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  type IntoIter = <heapless::IndexMap<K, V, S, N> as IntoIterator>::IntoIter;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> IntoIter for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type IntoIter = tinyvec::ArrayVecIterator<A>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> IntoIter for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type IntoIter = tinyvec::TinyVecIterator<A>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    IntoIterator::into_iter(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn sorted() {
+    assert_eq!(
+      <Sorted<[i32; 3]> as IntoIter>::into_iter(Sorted::new([2, 0, 1])).collect::<Vec<_>>(),
+      vec![0, 1, 2]
+    );
+  }
+  #[test]
+  fn array() {
+    assert_eq!(
+      <[i32; 2] as IntoIter>::into_iter([0, 1]).collect::<Vec<_>>(),
+      vec![0, 1]
+    );
+    assert_eq!(<[i32; 0] as IntoIter>::into_iter([]).next(), None);
+  }
+  #[test]
+  fn vec() {
+    assert_eq!(
+      <Vec<i32> as IntoIter>::into_iter(vec![0, 1]).collect::<Vec<_>>(),
+      vec![0, 1]
+    );
+  }
+  #[test]
+  fn vec_deque() {
+    assert_eq!(
+      <alloc::collections::VecDeque<i32> as IntoIter>::into_iter([0, 1].into()).collect::<Vec<_>>(),
+      vec![0, 1]
+    );
+  }
+  #[test]
+  fn linked_list() {
+    assert_eq!(
+      <alloc::collections::LinkedList<i32> as IntoIter>::into_iter([0, 1].into())
+        .collect::<Vec<_>>(),
+      vec![0, 1]
+    );
+  }
+  #[test]
+  fn binary_heap() {
+    assert_eq!(
+      <alloc::collections::BinaryHeap<i32> as IntoIter>::into_iter([1].into()).collect::<Vec<_>>(),
+      vec![1]
+    );
+  }
+  #[test]
+  fn b_tree_map() {
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, i32> as IntoIter>::into_iter([(0, 1)].into())
+        .collect::<Vec<_>>(),
+      vec![(0, 1)]
+    );
+  }
+  #[test]
+  fn b_tree_set() {
+    assert_eq!(
+      <alloc::collections::BTreeSet<i32> as IntoIter>::into_iter([1, 0].into()).collect::<Vec<_>>(),
+      vec![0, 1]
+    );
+  }
+  #[test]
+  fn hash_map() {
+    assert_eq!(
+      <std::collections::HashMap<i32, i32> as IntoIter>::into_iter([(0, 1)].into())
+        .collect::<Vec<_>>(),
+      vec![(0, 1)]
+    );
+  }
+  #[test]
+  fn hash_set() {
+    assert_eq!(
+      <std::collections::HashSet<i32> as IntoIter>::into_iter([0].into()).collect::<Vec<_>>(),
+      vec![0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dash_map() {
+    let m = dashmap::DashMap::new();
+    m.insert(0, 1);
+    assert_eq!(
+      <dashmap::DashMap<i32, i32> as IntoIter>::into_iter(m).collect::<Vec<_>>(),
+      vec![(0, 1)]
+    );
+  }
+  #[test]
+  fn dash_set() {
+    let s = dashmap::DashSet::new();
+    s.insert(0);
+    assert_eq!(
+      <dashmap::DashSet<i32> as IntoIter>::into_iter(s).collect::<Vec<_>>(),
+      vec![0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    assert_eq!(
+      IntoIter::into_iter(serde_json::json!([0, 1])).collect::<Vec<_>>(),
+      vec![serde_json::json!(0), serde_json::json!(1)]
+    );
+    assert_eq!(
+      IntoIter::into_iter(serde_json::json!({ "a": 0 })).collect::<Vec<_>>(),
+      vec![serde_json::json!(0)]
+    );
+    assert_eq!(IntoIter::into_iter(serde_json::Value::Null).next(), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  #[test]
+  fn simd_json_borrowed() {
+    let a = simd_json::BorrowedValue::Array(vec![simd_json::BorrowedValue::Static(
+      simd_json::StaticNode::Null,
+    )]);
+    assert_eq!(IntoIter::into_iter(a).count(), 1);
+    assert_eq!(
+      IntoIter::into_iter(simd_json::BorrowedValue::Static(
+        simd_json::StaticNode::Null
+      ))
+      .count(),
+      0
+    );
+  }
+  #[test]
+  fn simd_json_owned() {
+    let mut o = simd_json::value::owned::Object::new();
+    o.insert(
+      String::from("a"),
+      simd_json::OwnedValue::Static(simd_json::StaticNode::Null),
+    );
+    assert_eq!(
+      IntoIter::into_iter(simd_json::OwnedValue::Object(Box::new(o))).collect::<Vec<_>>(),
+      vec![simd_json::OwnedValue::Static(simd_json::StaticNode::Null)]
+    );
+    assert_eq!(
+      IntoIter::into_iter(simd_json::OwnedValue::Static(simd_json::StaticNode::Null)).count(),
+      0
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    s.insert(1);
+    assert_eq!(
+      <slab::Slab<i32> as IntoIter>::into_iter(s).collect::<Vec<_>>(),
+      vec![(0, 1)]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let m = indexmap::IndexMap::from([(1, 0), (0, 1)]);
+    assert_eq!(
+      <indexmap::IndexMap<i32, i32> as IntoIter>::into_iter(m).collect::<Vec<_>>(),
+      [(1, 0), (0, 1)]
+    );
+    let s = indexmap::IndexSet::from([1, 0]);
+    assert_eq!(
+      <indexmap::IndexSet<i32> as IntoIter>::into_iter(s).collect::<Vec<_>>(),
+      [1, 0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless_binary_heap() {
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Max, 2>::new();
+    h.push(1).unwrap();
+    assert_eq!(IntoIter::into_iter(h).collect::<heapless::Vec<_, 2>>(), [1]);
+  }
+}
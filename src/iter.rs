@@ -0,0 +1,678 @@
+use crate::{Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to iterate over the entries of a collection by reference.
+///
+/// Maps yield key-value pairs, while other collections yield their values. JSON values yield the items of arrays and the values of objects. To iterate by value, see [`IntoIter`](crate::IntoIter).
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeSet;
+/// use collectivity::Iter;
+///
+/// fn total<'a>(col: &'a impl Iter<Iter<'a> = impl Iterator<Item = &'a i32>>) -> i32 {
+///   col.iter().sum()
+/// }
+///
+/// assert_eq!(total(&vec![1, 2, 3]), 6);
+/// assert_eq!(total(&BTreeSet::from([1, 2, 3])), 6);
+/// # }
+/// ```
+pub trait Iter {
+  /// Indicates whether the `iter` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `iter`.
+  type Iter<'a>: Iterator
+  where
+    Self: 'a;
+  /// Returns an iterator over the entries of the collection.
+  fn iter<'a>(&'a self) -> Self::Iter<'a>;
+}
+
+/// Iterator over the children of a JSON value, i.e. the items of an array or the values of an object. Other values have no children.
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+#[derive(Debug)]
+pub enum JsonIter<A, O> {
+  /// Iterator over the items of an array.
+  Array(A),
+  /// Iterator over the values of an object.
+  Object(O),
+  /// Empty iterator for scalar values.
+  Empty,
+}
+
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+impl<A: Iterator, O: Iterator<Item = A::Item>> Iterator for JsonIter<A, O> {
+  type Item = A::Item;
+  fn next(&mut self) -> Option<A::Item> {
+    match self {
+      JsonIter::Array(a) => a.next(),
+      JsonIter::Object(o) => o.next(),
+      JsonIter::Empty => None,
+    }
+  }
+}
+
+impl<V> Iter for [V] {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+impl<V, const N: usize> Iter for [V; N] {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+impl<C: Iter> Iter for Sorted<C> {
+  type Safety = C::Safety;
+  type Iter<'a>
+    = C::Iter<'a>
+  where
+    Self: 'a;
+
+  /// Yields the values in ascending order
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    C::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Iter for Vec<V> {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Iter for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type Iter<'a>
+    = alloc::collections::vec_deque::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    alloc::collections::VecDeque::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Iter for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type Iter<'a>
+    = alloc::collections::linked_list::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    alloc::collections::LinkedList::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Iter for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  type Iter<'a>
+    = alloc::collections::binary_heap::Iter<'a, V>
+  where
+    Self: 'a;
+
+  /// Iterates in arbitrary order
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    alloc::collections::BinaryHeap::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Iter for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Iter<'a>
+    = alloc::collections::btree_map::Iter<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    alloc::collections::BTreeMap::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Iter for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type Iter<'a>
+    = alloc::collections::btree_set::Iter<'a, K>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    alloc::collections::BTreeSet::iter(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Iter for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = std::collections::hash_map::Iter<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    std::collections::HashMap::iter(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> Iter for std::collections::HashSet<K, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = std::collections::hash_set::Iter<'a, K>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    std::collections::HashSet::iter(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Iter for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = dashmap::iter::Iter<'a, K, V, S>
+  where
+    Self: 'a;
+
+  /// Yields guards holding read locks on the shards of the map
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    dashmap::DashMap::iter(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> Iter for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = dashmap::iter_set::Iter<'a, K, S, dashmap::DashMap<K, (), S>>
+  where
+    Self: 'a;
+
+  /// Yields guards holding read locks on the shards of the set
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    dashmap::DashSet::iter(self)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl Iter for serde_json::Value {
+  type Safety = Safe;
+  type Iter<'a>
+    = JsonIter<core::slice::Iter<'a, serde_json::Value>, serde_json::map::Values<'a>>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    match self {
+      serde_json::Value::Array(a) => JsonIter::Array(a.iter()),
+      serde_json::Value::Object(o) => JsonIter::Object(o.values()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> Iter for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type Iter<'a>
+    = JsonIter<
+    core::slice::Iter<'a, simd_json::BorrowedValue<'v>>,
+    halfbrown::Values<'a, simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>>,
+  >
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    match self {
+      simd_json::BorrowedValue::Array(a) => JsonIter::Array(a.iter()),
+      simd_json::BorrowedValue::Object(o) => JsonIter::Object(o.values()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Iter for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Iter<'a>
+    = JsonIter<
+    core::slice::Iter<'a, simd_json::OwnedValue>,
+    halfbrown::Values<'a, String, simd_json::OwnedValue>,
+  >
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    match self {
+      simd_json::OwnedValue::Array(a) => JsonIter::Array(a.iter()),
+      simd_json::OwnedValue::Object(o) => JsonIter::Object(o.values()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Iter for slab::Slab<V> {
+  type Safety = Safe;
+  type Iter<'a>
+    = slab::Iter<'a, V>
+  where
+    Self: 'a;
+
+  /// Yields key-value pairs, since `Slab` keys are not contiguous
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    slab::Slab::iter(self)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Iter for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[A::Item]>::iter(self)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Iter for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = indexmap::map::Iter<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    indexmap::IndexMap::iter(self)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Iter for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = indexmap::set::Iter<'a, K>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    indexmap::IndexSet::iter(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Iter for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = hashbrown::hash_map::Iter<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    hashbrown::HashMap::iter(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Iter for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  type Iter<'a>
+    = hashbrown::hash_set::Iter<'a, K>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    hashbrown::HashSet::iter(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Iter for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Iter for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Iter for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Iter<'a>
+    = <&'a heapless::Deque<V, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    heapless::Deque::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Iter
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    heapless::BinaryHeap::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Iter for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  type Iter<'a>
+    = heapless::IndexMapIter<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    heapless::IndexMap::iter(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Iter for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[A::Item]>::iter(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Iter for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Iter<'a>
+    = core::slice::Iter<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn iter<'a>(&'a self) -> Self::Iter<'a> {
+    <[A::Item]>::iter(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn sorted() {
+    assert_eq!(
+      <Sorted<[i32; 3]> as Iter>::iter(&Sorted::new([2, 0, 1])).collect::<Vec<_>>(),
+      vec![&0, &1, &2]
+    );
+  }
+  #[test]
+  fn array() {
+    assert_eq!(
+      <[i32] as Iter>::iter(&[0, 1]).collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+    assert_eq!(<[i32; 0] as Iter>::iter(&[]).next(), None);
+  }
+  #[test]
+  fn vec() {
+    assert_eq!(
+      <Vec<i32> as Iter>::iter(&vec![0, 1]).collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+  }
+  #[test]
+  fn vec_deque() {
+    assert_eq!(
+      <alloc::collections::VecDeque<i32> as Iter>::iter(&[0, 1].into()).collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+  }
+  #[test]
+  fn linked_list() {
+    assert_eq!(
+      <alloc::collections::LinkedList<i32> as Iter>::iter(&[0, 1].into()).collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+  }
+  #[test]
+  fn binary_heap() {
+    assert_eq!(
+      <alloc::collections::BinaryHeap<i32> as Iter>::iter(&[1].into()).collect::<Vec<_>>(),
+      vec![&1]
+    );
+  }
+  #[test]
+  fn b_tree_map() {
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, i32> as Iter>::iter(&[(0, 1)].into()).collect::<Vec<_>>(),
+      vec![(&0, &1)]
+    );
+  }
+  #[test]
+  fn b_tree_set() {
+    assert_eq!(
+      <alloc::collections::BTreeSet<i32> as Iter>::iter(&[1, 0].into()).collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+  }
+  #[test]
+  fn hash_map() {
+    assert_eq!(
+      <std::collections::HashMap<i32, i32> as Iter>::iter(&[(0, 1)].into()).collect::<Vec<_>>(),
+      vec![(&0, &1)]
+    );
+  }
+  #[test]
+  fn hash_set() {
+    assert_eq!(
+      <std::collections::HashSet<i32> as Iter>::iter(&[0].into()).collect::<Vec<_>>(),
+      vec![&0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dash_map() {
+    let m = dashmap::DashMap::new();
+    m.insert(0, 1);
+    assert_eq!(
+      <dashmap::DashMap<i32, i32> as Iter>::iter(&m)
+        .map(|r| (*r.key(), *r.value()))
+        .collect::<Vec<_>>(),
+      vec![(0, 1)]
+    );
+  }
+  #[test]
+  fn dash_set() {
+    let s = dashmap::DashSet::new();
+    s.insert(0);
+    assert_eq!(
+      <dashmap::DashSet<i32> as Iter>::iter(&s)
+        .map(|r| *r.key())
+        .collect::<Vec<_>>(),
+      vec![0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    assert_eq!(
+      Iter::iter(&serde_json::json!([0, 1])).collect::<Vec<_>>(),
+      vec![&serde_json::json!(0), &serde_json::json!(1)]
+    );
+    assert_eq!(
+      Iter::iter(&serde_json::json!({ "a": 0 })).collect::<Vec<_>>(),
+      vec![&serde_json::json!(0)]
+    );
+    assert_eq!(Iter::iter(&serde_json::Value::Null).next(), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  #[test]
+  fn simd_json_borrowed() {
+    let a = simd_json::BorrowedValue::Array(vec![simd_json::BorrowedValue::Static(
+      simd_json::StaticNode::Null,
+    )]);
+    assert_eq!(Iter::iter(&a).count(), 1);
+    assert_eq!(
+      Iter::iter(&simd_json::BorrowedValue::Static(
+        simd_json::StaticNode::Null
+      ))
+      .count(),
+      0
+    );
+  }
+  #[test]
+  fn simd_json_owned() {
+    let a = simd_json::OwnedValue::Array(vec![simd_json::OwnedValue::Static(
+      simd_json::StaticNode::Null,
+    )]);
+    assert_eq!(Iter::iter(&a).count(), 1);
+    assert_eq!(
+      Iter::iter(&simd_json::OwnedValue::Static(simd_json::StaticNode::Null)).count(),
+      0
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    s.insert(1);
+    assert_eq!(
+      <slab::Slab<i32> as Iter>::iter(&s).collect::<Vec<_>>(),
+      vec![(0, &1)]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn small_vec() {
+    let sv = smallvec::SmallVec::<[i32; 2]>::from_slice(&[0, 1]);
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 2]> as Iter>::iter(&sv).collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let m = indexmap::IndexMap::from([(1, 0), (0, 1)]);
+    assert_eq!(
+      <indexmap::IndexMap<i32, i32> as Iter>::iter(&m).collect::<Vec<_>>(),
+      [(&1, &0), (&0, &1)]
+    );
+    let s = indexmap::IndexSet::from([1, 0]);
+    assert_eq!(
+      <indexmap::IndexSet<i32> as Iter>::iter(&s).collect::<Vec<_>>(),
+      [&1, &0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let m = hashbrown::HashMap::from([(0, 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as Iter>::iter(&m).collect::<Vec<_>>(),
+      [(&0, &1)]
+    );
+    let s = hashbrown::HashSet::from([0]);
+    assert_eq!(
+      <hashbrown::HashSet<i32> as Iter>::iter(&s).collect::<Vec<_>>(),
+      [&0]
+    );
+  }
+}
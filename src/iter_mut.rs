@@ -0,0 +1,424 @@
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+use crate::JsonIter;
+use crate::{Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to iterate over the entries of a collection by mutable reference.
+///
+/// Maps yield pairs of shared keys and mutable values, while other collections yield their mutable values. JSON values yield the items of arrays and the values of objects.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::VecDeque;
+/// use collectivity::IterMut;
+///
+/// fn double<'a>(col: &'a mut impl IterMut<IterMut<'a> = impl Iterator<Item = &'a mut i32>>) {
+///   col.iter_mut().for_each(|v| *v *= 2);
+/// }
+///
+/// let mut v = vec![1, 2];
+/// double(&mut v);
+/// assert_eq!(v, vec![2, 4]);
+/// let mut v = VecDeque::from([1, 2]);
+/// double(&mut v);
+/// assert_eq!(v, VecDeque::from([2, 4]));
+/// # }
+/// ```
+pub trait IterMut {
+  /// Indicates whether the `iter_mut` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `iter_mut`.
+  type IterMut<'a>: Iterator
+  where
+    Self: 'a;
+  /// Returns an iterator over the entries of the collection that allows modifying the values.
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a>;
+}
+
+impl<V> IterMut for [V] {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[V]>::iter_mut(self)
+  }
+}
+
+impl<V, const N: usize> IterMut for [V; N] {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[V]>::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IterMut for Vec<V> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[V]>::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IterMut for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = alloc::collections::vec_deque::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    alloc::collections::VecDeque::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> IterMut for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = alloc::collections::linked_list::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    alloc::collections::LinkedList::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> IterMut for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = alloc::collections::btree_map::IterMut<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    alloc::collections::BTreeMap::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> IterMut for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = std::collections::hash_map::IterMut<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    std::collections::HashMap::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> IterMut for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = dashmap::iter::IterMut<'a, K, V, S>
+  where
+    Self: 'a;
+
+  /// Yields guards holding write locks on the shards of the map
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    dashmap::DashMap::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl IterMut for serde_json::Value {
+  type Safety = Safe;
+  type IterMut<'a>
+    = JsonIter<core::slice::IterMut<'a, serde_json::Value>, serde_json::map::ValuesMut<'a>>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    match self {
+      serde_json::Value::Array(a) => JsonIter::Array(a.iter_mut()),
+      serde_json::Value::Object(o) => JsonIter::Object(o.values_mut()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> IterMut for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = JsonIter<
+    core::slice::IterMut<'a, simd_json::BorrowedValue<'v>>,
+    halfbrown::ValuesMut<'a, simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>>,
+  >
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    match self {
+      simd_json::BorrowedValue::Array(a) => JsonIter::Array(a.iter_mut()),
+      simd_json::BorrowedValue::Object(o) => JsonIter::Object(o.values_mut()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl IterMut for simd_json::OwnedValue {
+  type Safety = Safe;
+  type IterMut<'a>
+    = JsonIter<
+    core::slice::IterMut<'a, simd_json::OwnedValue>,
+    halfbrown::ValuesMut<'a, String, simd_json::OwnedValue>,
+  >
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    match self {
+      simd_json::OwnedValue::Array(a) => JsonIter::Array(a.iter_mut()),
+      simd_json::OwnedValue::Object(o) => JsonIter::Object(o.values_mut()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> IterMut for slab::Slab<V> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = slab::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  /// Yields key-value pairs, since `Slab` keys are not contiguous
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    slab::Slab::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> IterMut for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[A::Item]>::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> IterMut for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = indexmap::map::IterMut<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    indexmap::IndexMap::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> IterMut for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = hashbrown::hash_map::IterMut<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    hashbrown::HashMap::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> IterMut for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[V]>::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> IterMut for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[V]>::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> IterMut for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = <&'a mut heapless::Deque<V, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    heapless::Deque::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> IterMut for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = heapless::IndexMapIterMut<'a, K, V>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    heapless::IndexMap::iter_mut(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> IterMut for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[A::Item]>::iter_mut(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> IterMut for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type IterMut<'a>
+    = core::slice::IterMut<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn iter_mut<'a>(&'a mut self) -> Self::IterMut<'a> {
+    <[A::Item]>::iter_mut(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn array() {
+    let mut a = [0, 1];
+    <[i32; 2] as IterMut>::iter_mut(&mut a).for_each(|v| *v += 1);
+    assert_eq!(a, [1, 2]);
+  }
+  #[test]
+  fn linked_list() {
+    let mut l = alloc::collections::LinkedList::from([0, 1]);
+    <alloc::collections::LinkedList<i32> as IterMut>::iter_mut(&mut l).for_each(|v| *v += 1);
+    assert_eq!(l, alloc::collections::LinkedList::from([1, 2]));
+  }
+  #[test]
+  fn b_tree_map() {
+    let mut m = alloc::collections::BTreeMap::from([(0, 0)]);
+    <alloc::collections::BTreeMap<i32, i32> as IterMut>::iter_mut(&mut m)
+      .for_each(|(_, v)| *v += 1);
+    assert_eq!(m[&0], 1);
+  }
+  #[test]
+  fn hash_map() {
+    let mut m = std::collections::HashMap::from([(0, 0)]);
+    <std::collections::HashMap<i32, i32> as IterMut>::iter_mut(&mut m).for_each(|(_, v)| *v += 1);
+    assert_eq!(m[&0], 1);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dash_map() {
+    let mut m = dashmap::DashMap::new();
+    m.insert(0, 0);
+    <dashmap::DashMap<i32, i32> as IterMut>::iter_mut(&mut m).for_each(|mut r| *r.value_mut() += 1);
+    assert_eq!(m.get(&0).map(|v| *v), Some(1));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut o = serde_json::json!({ "a": 0 });
+    IterMut::iter_mut(&mut o).for_each(|v| *v = serde_json::Value::Null);
+    assert_eq!(o, serde_json::json!({ "a": null }));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    s.insert(1);
+    <slab::Slab<i32> as IterMut>::iter_mut(&mut s).for_each(|(_, v)| *v += 1);
+    assert_eq!(s[0], 2);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = indexmap::IndexMap::from([(1, 0), (0, 1)]);
+    <indexmap::IndexMap<i32, i32> as IterMut>::iter_mut(&mut m).for_each(|(_, v)| *v += 1);
+    assert_eq!(m.values().collect::<Vec<_>>(), [&1, &2]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(0, 0)]);
+    <hashbrown::HashMap<i32, i32> as IterMut>::iter_mut(&mut m).for_each(|(_, v)| *v += 1);
+    assert_eq!(m[&0], 1);
+  }
+}
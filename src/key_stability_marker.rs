@@ -0,0 +1,10 @@
+/// Groups types used to indicate whether a trait implementation may change the keys of existing entries
+pub trait KeyStabilityMarker {}
+
+/// Indicates a trait implementation leaves the keys of other entries unchanged
+pub struct Stable {}
+impl KeyStabilityMarker for Stable {}
+
+/// Indicates a trait implementation may shift the keys of other entries, e.g. the indexes following an inserted or removed `Vec` item
+pub struct Shifting {}
+impl KeyStabilityMarker for Shifting {}
@@ -0,0 +1,392 @@
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+use crate::JsonIter;
+use crate::{Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to iterate over the keys of a collection. The yielded keys can be passed to `Get` and `GetMut`.
+///
+/// Index-based collections yield the range of their valid indexes. JSON values yield the keys of objects only, since their array indexes have a different type.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::{Get, Keys};
+///
+/// fn sum<'a, K, C>(col: &'a C) -> i32
+/// where
+///   C: Keys<Keys<'a> = K> + Get<K::Item, Value<'a> = &'a i32>,
+///   K: Iterator,
+/// {
+///   col.keys().filter_map(|k| col.get(k)).sum()
+/// }
+///
+/// assert_eq!(sum(&vec![1, 2]), 3);
+/// assert_eq!(sum(&HashMap::from([("A", 1), ("B", 2)])), 3);
+/// # }
+/// ```
+pub trait Keys {
+  /// Indicates whether the `keys` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `keys`.
+  type Keys<'a>: Iterator
+  where
+    Self: 'a;
+  /// Returns an iterator over the keys of the collection.
+  fn keys<'a>(&'a self) -> Self::Keys<'a>;
+}
+
+impl<V> Keys for [V] {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+impl<V, const N: usize> Keys for [V; N] {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..N
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Keys for Vec<V> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Keys for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Keys for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Keys for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Keys<'a>
+    = alloc::collections::btree_map::Keys<'a, K, V>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    alloc::collections::BTreeMap::keys(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Keys for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Keys<'a>
+    = std::collections::hash_map::Keys<'a, K, V>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    std::collections::HashMap::keys(self)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl Keys for serde_json::Value {
+  type Safety = Safe;
+  type Keys<'a>
+    = JsonIter<core::iter::Empty<&'a String>, serde_json::map::Keys<'a>>
+  where
+    Self: 'a;
+
+  /// Yields nothing if the value is not an object
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    match self {
+      serde_json::Value::Object(o) => JsonIter::Object(o.keys()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> Keys for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type Keys<'a>
+    = JsonIter<
+    core::iter::Empty<&'a simd_json::cow::Cow<'v, str>>,
+    halfbrown::Keys<'a, simd_json::cow::Cow<'v, str>, simd_json::BorrowedValue<'v>>,
+  >
+  where
+    Self: 'a;
+
+  /// Yields nothing if the value is not an object
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    match self {
+      simd_json::BorrowedValue::Object(o) => JsonIter::Object(o.keys()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Keys for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Keys<'a>
+    = JsonIter<core::iter::Empty<&'a String>, halfbrown::Keys<'a, String, simd_json::OwnedValue>>
+  where
+    Self: 'a;
+
+  /// Yields nothing if the value is not an object
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    match self {
+      simd_json::OwnedValue::Object(o) => JsonIter::Object(o.keys()),
+      _ => JsonIter::Empty,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Keys for slab::Slab<V> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::iter::Map<slab::Iter<'a, V>, fn((usize, &'a V)) -> usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    slab::Slab::iter(self).map(|(k, _)| k)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Keys for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Keys for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Keys<'a>
+    = indexmap::map::Keys<'a, K, V>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    indexmap::IndexMap::keys(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Keys for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Keys<'a>
+    = hashbrown::hash_map::Keys<'a, K, V>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    hashbrown::HashMap::keys(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Keys for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Keys for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Keys for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..heapless::Deque::len(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Keys for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  type Keys<'a>
+    = heapless::IndexMapKeys<'a, K, V>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    heapless::IndexMap::keys(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Keys for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Keys for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Keys<'a>
+    = core::ops::Range<usize>
+  where
+    Self: 'a;
+
+  fn keys<'a>(&'a self) -> Self::Keys<'a> {
+    0..self.len()
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!(<[i32; 2] as Keys>::keys(&[5, 6]), 0..2);
+    assert_eq!(<Vec<i32> as Keys>::keys(&vec![]), 0..0);
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, ()> as Keys>::keys(&[(1, ()), (0, ())].into())
+        .collect::<Vec<_>>(),
+      vec![&0, &1]
+    );
+    assert_eq!(
+      <std::collections::HashMap<i32, ()> as Keys>::keys(&[(0, ())].into()).collect::<Vec<_>>(),
+      vec![&0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    assert_eq!(
+      Keys::keys(&serde_json::json!({ "a": 0 })).collect::<Vec<_>>(),
+      vec!["a"]
+    );
+    assert_eq!(Keys::keys(&serde_json::json!([0])).next(), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    s.insert(0);
+    s.insert(1);
+    s.remove(0);
+    assert_eq!(
+      <slab::Slab<i32> as Keys>::keys(&s).collect::<Vec<_>>(),
+      vec![1]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let m = indexmap::IndexMap::from([(1, 0), (0, 1)]);
+    assert_eq!(
+      <indexmap::IndexMap<i32, i32> as Keys>::keys(&m).collect::<Vec<_>>(),
+      [&1, &0]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let m = hashbrown::HashMap::from([(0, 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as Keys>::keys(&m).collect::<Vec<_>>(),
+      [&0]
+    );
+  }
+}
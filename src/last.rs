@@ -0,0 +1,151 @@
+use crate::{Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Provides access to the entry with the greatest key of an ordered collection.
+///
+/// Maps yield key-value pairs, while other collections yield their values. `BinaryHeap` only supports this trait, as it only exposes its greatest value.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{BTreeMap, BTreeSet};
+/// use collectivity::{Last, Sorted};
+///
+/// fn last<'a, C: Last<i32, Value<'a> = &'a i32>>(col: &'a C) -> Option<&'a i32> {
+///   col.last()
+/// }
+///
+/// assert_eq!(last(&BTreeSet::from([2, 1, 3])), Some(&3));
+/// assert_eq!(last(&Sorted::new([2, 1, 3])), Some(&3));
+/// assert_eq!(BTreeMap::from([(1, "a"), (2, "b")]).last(), Some((&2, &"b")));
+/// # }
+/// ```
+pub trait Last<V> {
+  /// Indicates whether the `last` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the value returned by `last`.
+  type Value<'a>
+  where
+    Self: 'a;
+  /// Returns the `Option`-wrapped entry with the greatest key, or `None` if the collection is empty.
+  fn last<'a>(&'a self) -> Option<Self::Value<'a>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Last<(K, V)> for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Value<'a>
+    = (&'a K, &'a V)
+  where
+    Self: 'a;
+
+  fn last(&self) -> Option<(&K, &V)> {
+    self.last_key_value()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Last<K> for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn last(&self) -> Option<&K> {
+    alloc::collections::BTreeSet::last(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Last<V> for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn last(&self) -> Option<&V> {
+    self.peek()
+  }
+}
+
+impl<V: Ord, const N: usize> Last<V> for Sorted<[V; N]> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn last(&self) -> Option<&V> {
+    <[V]>::last(&self[..])
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Last<V> for Sorted<Vec<V>> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn last(&self) -> Option<&V> {
+    <[V]>::last(&self[..])
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, const N: usize> Last<V> for heapless::BinaryHeap<V, heapless::binary_heap::Max, N> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn last(&self) -> Option<&V> {
+    heapless::BinaryHeap::peek(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, i32> as Last<_>>::last(&Default::default()),
+      None
+    );
+    assert_eq!(
+      <alloc::collections::BTreeSet<i32> as Last<_>>::last(&[1, 3, 2].into()),
+      Some(&3)
+    );
+    assert_eq!(
+      <alloc::collections::BinaryHeap<i32> as Last<_>>::last(&[1, 3, 2].into()),
+      Some(&3)
+    );
+  }
+  #[test]
+  fn sorted() {
+    assert_eq!(
+      <Sorted<Vec<i32>> as Last<_>>::last(&Sorted::new(vec![1, 3, 2])),
+      Some(&3)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Max, 4>::new();
+    h.push(1).unwrap();
+    h.push(2).unwrap();
+    assert_eq!(Last::last(&h), Some(&2));
+  }
+}
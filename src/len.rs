@@ -1,10 +1,14 @@
-#[cfg(feature = "std")]
-use core::hash::Hash;
+use crate::{ComplexityMarker, Constant, Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
 
 /// Provides information about the number of entries in a collection.
 ///
 /// ## Examples
 /// ```
+/// # #[cfg(feature = "std")] {
 /// use collectivity::Len;
 ///
 /// fn len(
@@ -15,94 +19,135 @@ use core::hash::Hash;
 ///
 /// assert_eq!(len(&std::collections::HashSet::<()>::new()), 0);
 /// assert_eq!(len(&vec![1, 2, 3]), 3);
+/// # }
 /// ```
+#[allow(clippy::len_without_is_empty)]
 pub trait Len {
+  /// Indicates whether the `len` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Indicates the time complexity of the `len` method in a particular implementation.
+  type Complexity: ComplexityMarker;
   /// Returns the number of entries in the collection
   fn len(&self) -> usize;
 }
 
 impl<L: Len> Len for &L {
+  type Safety = <L as Len>::Safety;
+  type Complexity = <L as Len>::Complexity;
   fn len(&self) -> usize {
     <L as Len>::len(self)
   }
 }
 
 impl<V> Len for [V] {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
 impl<V, const N: usize> Len for [V; N] {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     <[V]>::len(self)
   }
 }
 
+impl<C: Len> Len for Sorted<C> {
+  type Safety = C::Safety;
+  type Complexity = C::Complexity;
+  fn len(&self) -> usize {
+    C::len(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
 impl<V> Len for Vec<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
-#[cfg(feature = "std")]
-impl<V> Len for std::collections::VecDeque<V> {
+#[cfg(feature = "alloc")]
+impl<V> Len for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
-#[cfg(feature = "std")]
-impl<V> Len for std::collections::LinkedList<V> {
+#[cfg(feature = "alloc")]
+impl<V> Len for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
-#[cfg(feature = "std")]
-impl<V> Len for std::collections::BinaryHeap<V> {
+#[cfg(feature = "alloc")]
+impl<V> Len for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     Self::len(self)
   }
 }
 
-#[cfg(feature = "std")]
-impl<K: Ord, V> Len for std::collections::BTreeMap<K, V> {
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Len for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
-#[cfg(feature = "std")]
-impl<K: Ord> Len for std::collections::BTreeSet<K> {
+#[cfg(feature = "alloc")]
+impl<K: Ord> Len for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash, V> Len for std::collections::HashMap<K, V> {
+impl<K: Eq + Hash, V, S: BuildHasher> Len for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash> Len for std::collections::HashSet<K> {
+impl<K: Eq + Hash, S: BuildHasher> Len for std::collections::HashSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash, V> Len for dashmap::DashMap<K, V> {
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Len for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash> Len for dashmap::DashSet<K> {
+impl<K: Eq + Hash, S: BuildHasher + Clone> Len for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
@@ -110,6 +155,8 @@ impl<K: Eq + Hash> Len for dashmap::DashSet<K> {
 
 #[cfg(feature = "serde_json")]
 impl Len for serde_json::Value {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     match self {
       serde_json::Value::Array(a) => a.len(),
@@ -121,6 +168,8 @@ impl Len for serde_json::Value {
 
 #[cfg(feature = "simd-json")]
 impl Len for simd_json::BorrowedValue<'_> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     match self {
       simd_json::BorrowedValue::Array(a) => a.len(),
@@ -132,6 +181,8 @@ impl Len for simd_json::BorrowedValue<'_> {
 
 #[cfg(feature = "simd-json")]
 impl Len for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     match self {
       simd_json::OwnedValue::Array(a) => a.len(),
@@ -143,6 +194,8 @@ impl Len for simd_json::OwnedValue {
 
 #[cfg(feature = "slab")]
 impl<V> Len for slab::Slab<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
@@ -150,7 +203,142 @@ impl<V> Len for slab::Slab<V> {
 
 #[cfg(feature = "smallvec")]
 impl<V: smallvec::Array> Len for smallvec::SmallVec<V> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    self.len()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Len for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    self.len()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Len for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
   fn len(&self) -> usize {
     self.len()
   }
 }
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Len for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    hashbrown::HashMap::len(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Len for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    hashbrown::HashSet::len(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Len for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    <[V]>::len(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Len for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    <[V]>::len(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Len for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    heapless::Deque::len(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Len for heapless::BinaryHeap<V, K, N> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    heapless::BinaryHeap::len(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Len for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    heapless::IndexMap::len(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Len for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    <[A::Item]>::len(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Len for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  fn len(&self) -> usize {
+    <[A::Item]>::len(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    assert_eq!(
+      <indexmap::IndexMap<i32, i32> as Len>::len(&indexmap::IndexMap::from([(0, 1)])),
+      1
+    );
+    assert_eq!(
+      <indexmap::IndexSet<i32> as Len>::len(&Default::default()),
+      0
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as Len>::len(&hashbrown::HashMap::from([(0, 1)])),
+      1
+    );
+    assert_eq!(
+      <hashbrown::HashSet<i32> as Len>::len(&hashbrown::HashSet::new()),
+      0
+    );
+  }
+}
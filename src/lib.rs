@@ -1,4 +1,4 @@
-#![feature(generic_associated_types, associated_type_defaults)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(missing_docs)]
 //! Generic collection traits. The crate contains definitions of various traits related to data collections, as well as their implementations for arrays, slices, and collection types from both the standard library and a selection of popular community crates.
 //!
@@ -6,35 +6,38 @@
 //!
 //! Most of the abstracted behaviors are already implemented by the underlying containers. In such cases, the provided trait implementations simply delegate to appropriate methods while standardizing argument and return types.
 //!
-//! **At this point, the crate should be considered experimental. It relies on the unstable `generic_associated_types` and `associated_type_defaults` features. The API might change rapidly, but the project conforms to semver and no breaking API changes should be expected within a major release.**
+//! **At this point, the crate should be considered experimental. The API might change rapidly, but the project conforms to semver and no breaking API changes should be expected within a major release.**
+//!
+//! The crate supports `no_std`. The `alloc` feature enables implementations for `Vec` and the `alloc::collections` types, while the default `std` feature adds `HashMap` and `HashSet`. Without either, only arrays and slices are covered. The `hashbrown` feature only requires `alloc`, so `hashbrown::HashMap` and `HashSet` can stand in for the standard maps in `no_std` builds. The `arrayvec`, `heapless` and `tinyvec` features need no allocator at all; their collections have a fixed capacity, so `Push` and `Insert` panic when full while `TryPush`, `TryInsert` and `TryReserve` report `CapacityExceeded`.
 //!
 //! # Example
 //! ```
-//! #![feature(box_syntax)]
-//!
-//! use collectivity::{nosafety::Insert, Len};
+//! use collectivity::nosafety::{ExtendInsert, Len};
 //! use std::{
 //!   collections::{BTreeMap, HashMap, VecDeque},
 //!   time::Instant,
 //! };
 //!
-//! pub trait MyTraitSelection<K, V>: Insert<K, V> + Len {}
+//! pub trait MyTraitSelection<K, V>: ExtendInsert<K, V> + Len {}
 //!
-//! impl<K, V, C: Insert<K, V> + Len> MyTraitSelection<K, V> for C {}
+//! impl<K, V, C: ExtendInsert<K, V> + Len> MyTraitSelection<K, V> for C {}
 //!
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! # #[cfg(feature = "std")]
 //! fn main() {
 //!   const N: usize = 10_000_000;
 //!   let data = (0..N).map(|n| (n, n)).collect::<Vec<_>>();
 //!   let collections: &mut [(&str, Box<dyn MyTraitSelection<_, _>>)] = &mut [
-//!     ("Array", box [0_usize; N] as _),
-//!     ("Vec", box vec![] as _),
-//!     ("VecDeque", box VecDeque::new() as _),
-//!     ("BTreeMap", box BTreeMap::new() as _),
-//!     ("HashMap", box HashMap::new() as _),
+//!     ("Array", Box::<[usize; N]>::try_from(vec![0; N]).unwrap() as _),
+//!     ("Vec", Box::new(vec![]) as _),
+//!     ("VecDeque", Box::new(VecDeque::new()) as _),
+//!     ("BTreeMap", Box::new(BTreeMap::new()) as _),
+//!     ("HashMap", Box::new(HashMap::new()) as _),
 //!   ];
 //!   collections.iter_mut().for_each(|(name, c)| {
 //!     let t = Instant::now();
-//!     data.iter().for_each(|(k, v)| c.insert(*k, *v));
+//!     c.extend_insert(&mut data.iter().copied());
 //!     println!(
 //!       "{:<10}: inserted in {:<15}, len: {}",
 //!       name,
@@ -44,21 +47,107 @@
 //!   });
 //! }
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Object-safe facades of traits with generic associated types
+pub mod dyn_traits;
 /// Traits without safety information
 pub mod nosafety;
+/// Bounds accepting only implementations that may not panic
+pub mod safe;
 
+mod capacity;
+mod clear;
+mod collect_from;
+mod collection_error;
+mod complexity_marker;
+mod drain;
+mod drain_range;
+mod entry;
+mod extend_insert;
+mod extend_push;
+mod first;
 mod get;
+mod get_mut;
 mod insert;
+mod into_iter;
+mod iter;
+mod iter_mut;
+mod key_stability_marker;
+mod keys;
+mod last;
 mod len;
+mod ordered;
+mod peek;
+mod pop;
+mod pop_back;
+mod pop_first;
+mod pop_front;
+mod pop_last;
+mod position;
 mod push;
+mod push_front;
+mod range;
 mod remove;
+mod reserve;
+mod retain;
 mod safety_marker;
+mod sorted;
+mod stable_keys;
+mod sub_linear;
+mod swap;
+mod try_get;
 mod try_insert;
+mod try_push;
+mod try_remove;
+mod try_reserve;
+mod values;
 
+pub use capacity::*;
+pub use clear::*;
+pub use collect_from::*;
+pub use collection_error::*;
+pub use complexity_marker::*;
+pub use drain::*;
+pub use drain_range::*;
+pub use entry::*;
+pub use extend_insert::*;
+pub use extend_push::*;
+pub use first::*;
 pub use get::*;
+pub use get_mut::*;
 pub use insert::*;
+pub use into_iter::*;
+pub use iter::*;
+pub use iter_mut::*;
+pub use key_stability_marker::*;
+pub use keys::*;
+pub use last::*;
 pub use len::*;
+pub use ordered::*;
+pub use peek::*;
+pub use pop::*;
+pub use pop_back::*;
+pub use pop_first::*;
+pub use pop_front::*;
+pub use pop_last::*;
+pub use position::*;
 pub use push::*;
+pub use push_front::*;
+pub use range::*;
 pub use remove::*;
+pub use reserve::*;
+pub use retain::*;
+pub use safe::*;
 pub use safety_marker::*;
+pub use sorted::*;
+pub use stable_keys::*;
+pub use sub_linear::*;
+pub use swap::*;
+pub use try_get::*;
 pub use try_insert::*;
+pub use try_push::*;
+pub use try_remove::*;
+pub use try_reserve::*;
+pub use values::*;
@@ -0,0 +1,13 @@
+use crate::Capacity as CapacityWithSafety;
+
+/// `Capacity` without safety information
+pub trait Capacity {
+  /// `capacity` without safety information
+  fn capacity(&self) -> usize;
+}
+
+impl<C: CapacityWithSafety> Capacity for C {
+  fn capacity(&self) -> usize {
+    C::capacity(self)
+  }
+}
@@ -0,0 +1,13 @@
+use crate::Clear as ClearWithSafety;
+
+/// `Clear` without safety information
+pub trait Clear {
+  /// `clear` without safety information
+  fn clear(&mut self);
+}
+
+impl<C: ClearWithSafety> Clear for C {
+  fn clear(&mut self) {
+    C::clear(self)
+  }
+}
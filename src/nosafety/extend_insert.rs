@@ -0,0 +1,13 @@
+use crate::ExtendInsert as ExtendInsertWithSafety;
+
+/// `ExtendInsert` without safety information
+pub trait ExtendInsert<K, V> {
+  /// `extend_insert` without safety information
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>);
+}
+
+impl<K, V, I: ExtendInsertWithSafety<K, V>> ExtendInsert<K, V> for I {
+  fn extend_insert(&mut self, iter: &mut dyn Iterator<Item = (K, V)>) {
+    I::extend_insert(self, iter)
+  }
+}
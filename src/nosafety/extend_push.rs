@@ -0,0 +1,13 @@
+use crate::ExtendPush as ExtendPushWithSafety;
+
+/// `ExtendPush` without safety information
+pub trait ExtendPush<V> {
+  /// `extend_push` without safety information
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>);
+}
+
+impl<V, P: ExtendPushWithSafety<V>> ExtendPush<V> for P {
+  fn extend_push(&mut self, iter: &mut dyn Iterator<Item = V>) {
+    P::extend_push(self, iter)
+  }
+}
@@ -0,0 +1,14 @@
+use crate::Len as LenWithSafety;
+
+/// `Len` without safety information
+#[allow(clippy::len_without_is_empty)]
+pub trait Len {
+  /// `len` without safety information
+  fn len(&self) -> usize;
+}
+
+impl<L: LenWithSafety> Len for L {
+  fn len(&self) -> usize {
+    L::len(self)
+  }
+}
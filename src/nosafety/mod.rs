@@ -1,7 +1,41 @@
+mod capacity;
+mod clear;
+mod extend_insert;
+mod extend_push;
 mod insert;
+mod len;
+mod pop;
+mod pop_back;
+mod pop_first;
+mod pop_front;
+mod pop_last;
 mod push;
+mod push_front;
 mod remove;
+mod reserve;
+mod retain;
+mod try_insert;
+mod try_push;
+mod try_remove;
+mod try_reserve;
 
+pub use capacity::*;
+pub use clear::*;
+pub use extend_insert::*;
+pub use extend_push::*;
 pub use insert::*;
+pub use len::*;
+pub use pop::*;
+pub use pop_back::*;
+pub use pop_first::*;
+pub use pop_front::*;
+pub use pop_last::*;
 pub use push::*;
+pub use push_front::*;
 pub use remove::*;
+pub use reserve::*;
+pub use retain::*;
+pub use try_insert::*;
+pub use try_push::*;
+pub use try_remove::*;
+pub use try_reserve::*;
@@ -0,0 +1,13 @@
+use crate::Pop as PopWithSafety;
+
+/// `Pop` without safety information
+pub trait Pop<V> {
+  /// `pop` without safety information
+  fn pop(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopWithSafety<V>> Pop<V> for P {
+  fn pop(&mut self) -> Option<V> {
+    P::pop(self)
+  }
+}
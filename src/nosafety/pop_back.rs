@@ -0,0 +1,13 @@
+use crate::PopBack as PopBackWithSafety;
+
+/// `PopBack` without safety information
+pub trait PopBack<V> {
+  /// `pop_back` without safety information
+  fn pop_back(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopBackWithSafety<V>> PopBack<V> for P {
+  fn pop_back(&mut self) -> Option<V> {
+    P::pop_back(self)
+  }
+}
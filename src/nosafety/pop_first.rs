@@ -0,0 +1,13 @@
+use crate::PopFirst as PopFirstWithSafety;
+
+/// `PopFirst` without safety information
+pub trait PopFirst<V> {
+  /// `pop_first` without safety information
+  fn pop_first(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopFirstWithSafety<V>> PopFirst<V> for P {
+  fn pop_first(&mut self) -> Option<V> {
+    P::pop_first(self)
+  }
+}
@@ -0,0 +1,13 @@
+use crate::PopFront as PopFrontWithSafety;
+
+/// `PopFront` without safety information
+pub trait PopFront<V> {
+  /// `pop_front` without safety information
+  fn pop_front(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopFrontWithSafety<V>> PopFront<V> for P {
+  fn pop_front(&mut self) -> Option<V> {
+    P::pop_front(self)
+  }
+}
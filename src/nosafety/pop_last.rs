@@ -0,0 +1,13 @@
+use crate::PopLast as PopLastWithSafety;
+
+/// `PopLast` without safety information
+pub trait PopLast<V> {
+  /// `pop_last` without safety information
+  fn pop_last(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopLastWithSafety<V>> PopLast<V> for P {
+  fn pop_last(&mut self) -> Option<V> {
+    P::pop_last(self)
+  }
+}
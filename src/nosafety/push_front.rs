@@ -0,0 +1,13 @@
+use crate::PushFront as PushFrontWithSafety;
+
+/// `PushFront` without safety information
+pub trait PushFront<V> {
+  /// `push_front` without safety information
+  fn push_front(&mut self, v: V);
+}
+
+impl<V, P: PushFrontWithSafety<V>> PushFront<V> for P {
+  fn push_front(&mut self, v: V) {
+    P::push_front(self, v)
+  }
+}
@@ -0,0 +1,23 @@
+use crate::Reserve as ReserveWithSafety;
+
+/// `Reserve` without safety information
+pub trait Reserve {
+  /// `reserve` without safety information
+  fn reserve(&mut self, additional: usize);
+  /// `reserve_exact` without safety information
+  fn reserve_exact(&mut self, additional: usize);
+  /// `shrink_to_fit` without safety information
+  fn shrink_to_fit(&mut self);
+}
+
+impl<R: ReserveWithSafety> Reserve for R {
+  fn reserve(&mut self, additional: usize) {
+    R::reserve(self, additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    R::reserve_exact(self, additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    R::shrink_to_fit(self)
+  }
+}
@@ -0,0 +1,13 @@
+use crate::Retain as RetainWithSafety;
+
+/// `Retain` without safety information
+pub trait Retain<V> {
+  /// `retain` without safety information
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool);
+}
+
+impl<V, R: RetainWithSafety<V>> Retain<V> for R {
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    R::retain(self, f)
+  }
+}
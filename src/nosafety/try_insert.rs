@@ -0,0 +1,13 @@
+use crate::{CollectionError, TryInsert as TryInsertWithSafety};
+
+/// `TryInsert` without safety information
+pub trait TryInsert<K, V> {
+  /// `try_insert` without safety information
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError>;
+}
+
+impl<K, V, T: TryInsertWithSafety<K, V>> TryInsert<K, V> for T {
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
+    T::try_insert(self, k, v)
+  }
+}
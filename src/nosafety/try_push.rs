@@ -0,0 +1,13 @@
+use crate::{CollectionError, TryPush as TryPushWithSafety};
+
+/// `TryPush` without safety information
+pub trait TryPush<V> {
+  /// `try_push` without safety information
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError>;
+}
+
+impl<V, P: TryPushWithSafety<V>> TryPush<V> for P {
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    P::try_push(self, v)
+  }
+}
@@ -0,0 +1,13 @@
+use crate::{CollectionError, TryRemove as TryRemoveWithSafety};
+
+/// `TryRemove` without safety information
+pub trait TryRemove<K, V> {
+  /// `try_remove` without safety information
+  fn try_remove(&mut self, k: K) -> Result<V, CollectionError>;
+}
+
+impl<K, V, R: TryRemoveWithSafety<K, V>> TryRemove<K, V> for R {
+  fn try_remove(&mut self, k: K) -> Result<V, CollectionError> {
+    R::try_remove(self, k)
+  }
+}
@@ -0,0 +1,18 @@
+use crate::{CollectionError, TryReserve as TryReserveWithSafety};
+
+/// `TryReserve` without safety information
+pub trait TryReserve {
+  /// `try_reserve` without safety information
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError>;
+  /// `try_reserve_exact` without safety information
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError>;
+}
+
+impl<R: TryReserveWithSafety> TryReserve for R {
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    R::try_reserve(self, additional)
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    R::try_reserve_exact(self, additional)
+  }
+}
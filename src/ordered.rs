@@ -0,0 +1,28 @@
+/// Marks collections that keep their entries sorted by key.
+///
+/// Iterating over an `Ordered` collection, as well as its `First`, `Last` and `Range` implementations, follow the key order.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeSet;
+/// use collectivity::{Iter, Ordered, Sorted};
+///
+/// fn is_sorted<'a, C: Ordered + Iter<Iter<'a> = impl Iterator<Item = &'a i32>>>(col: &'a C) -> bool {
+///   let v = col.iter().collect::<Vec<_>>();
+///   v.windows(2).all(|w| w[0] <= w[1])
+/// }
+///
+/// assert!(is_sorted(&BTreeSet::from([3, 1, 2])));
+/// assert!(is_sorted(&Sorted::new(vec![3, 1, 2])));
+/// # }
+/// ```
+pub trait Ordered {}
+
+impl<O: Ordered> Ordered for &O {}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Ordered for alloc::collections::BTreeMap<K, V> {}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Ordered for alloc::collections::BTreeSet<K> {}
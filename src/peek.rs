@@ -0,0 +1,329 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// Provides access to the value that `Pop` would take out of the collection next.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{BinaryHeap, VecDeque};
+/// use collectivity::{Peek, Push};
+///
+/// fn push_peek<'a, C: Push<i32> + Peek<i32, Value<'a> = &'a i32>>(col: &'a mut C) -> Option<&'a i32> {
+///   col.push(1);
+///   col.push(2);
+///   col.peek()
+/// }
+///
+/// assert_eq!(push_peek(&mut vec![]), Some(&2));
+/// assert_eq!(push_peek(&mut VecDeque::new()), Some(&1));
+/// assert_eq!(push_peek(&mut BinaryHeap::new()), Some(&2));
+/// # }
+/// ```
+pub trait Peek<V> {
+  /// Indicates whether the `peek` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the value returned by `peek`.
+  type Value<'a>
+  where
+    Self: 'a;
+  /// Returns the `Option`-wrapped value that would be popped next, or `None` if the collection is empty.
+  fn peek<'a>(&'a self) -> Option<Self::Value<'a>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Peek<V> for Vec<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    self.last()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Peek<V> for VecDeque<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    self.front()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Peek<V> for BinaryHeap<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    BinaryHeap::peek(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Peek<V> for LinkedList<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    self.front()
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl Peek<SeV> for SeV {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SeV
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an array or the array is empty
+  fn peek(&self) -> Option<&SeV> {
+    match self {
+      SeV::Array(a) => a.last(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'v> Peek<SBV<'v>> for SBV<'v> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SBV<'v>
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an array or the array is empty
+  fn peek<'a>(&'a self) -> Option<&'a SBV<'v>> {
+    match self {
+      SBV::Array(a) => a.last(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Peek<SOV> for SOV {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SOV
+  where
+    Self: 'a;
+
+  /// Returns `None` if the value is not an array or the array is empty
+  fn peek(&self) -> Option<&SOV> {
+    match self {
+      SOV::Array(a) => a.last(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+use slab::Slab;
+
+#[cfg(feature = "slab")]
+impl<V> Peek<V> for Slab<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  /// Returns the entry with the greatest key, matching `Pop`
+  fn peek(&self) -> Option<&V> {
+    self.iter().next_back().map(|(_, v)| v)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+use smallvec::{Array, SmallVec};
+
+#[cfg(feature = "smallvec")]
+impl<A: Array> Peek<A::Item> for SmallVec<A> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&A::Item> {
+    self.last()
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Peek<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    <[V]>::last(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Peek<V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    <[V]>::last(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Peek<V> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    heapless::Deque::front(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Peek<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&V> {
+    heapless::BinaryHeap::peek(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Peek<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&A::Item> {
+    <[A::Item]>::last(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Peek<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn peek(&self) -> Option<&A::Item> {
+    <[A::Item]>::last(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!(<Vec<i32> as Peek<i32>>::peek(&vec![1, 2]), Some(&2));
+    assert_eq!(
+      <VecDeque<i32> as Peek<i32>>::peek(&VecDeque::from([1, 2])),
+      Some(&1)
+    );
+    assert_eq!(
+      <BinaryHeap<i32> as Peek<i32>>::peek(&BinaryHeap::from([1, 3, 2])),
+      Some(&3)
+    );
+    assert_eq!(
+      <LinkedList<i32> as Peek<i32>>::peek(&LinkedList::from([1, 2])),
+      Some(&1)
+    );
+    assert_eq!(
+      <LinkedList<i32> as Peek<i32>>::peek(&LinkedList::new()),
+      None
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    assert_eq!(
+      <SeV as Peek<SeV>>::peek(&SeV::Array(vec![SeV::Null])),
+      Some(&SeV::Null)
+    );
+    assert_eq!(<SeV as Peek<SeV>>::peek(&SeV::Null), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = Slab::new();
+    s.insert(1);
+    s.insert(2);
+    assert_eq!(<Slab<i32> as Peek<i32>>::peek(&s), Some(&2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let sv = SmallVec::<[i32; 2]>::from_slice(&[1, 2]);
+    assert_eq!(<SmallVec<[i32; 2]> as Peek<i32>>::peek(&sv), Some(&2));
+  }
+}
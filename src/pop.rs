@@ -0,0 +1,292 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// Provides the ability to take a value out of a collection. It's the semantic counterpart of `Push`.
+///
+/// The exact semantics of the pop behavior may differ among collections, e.g. `Vec` behaves like a stack, `VecDeque` and `LinkedList` behave like queues, and `BinaryHeap` returns its greatest item.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{BinaryHeap, VecDeque};
+/// use collectivity::{Pop, Push};
+///
+/// fn push_pop<C: Push<i32> + Pop<i32>>(col: &mut C) -> Option<i32> {
+///   col.push(1);
+///   col.push(2);
+///   col.pop()
+/// }
+///
+/// assert_eq!(push_pop(&mut vec![]), Some(2));
+/// assert_eq!(push_pop(&mut VecDeque::new()), Some(1));
+/// assert_eq!(push_pop(&mut BinaryHeap::new()), Some(2));
+/// # }
+/// ```
+pub trait Pop<V> {
+  /// Indicates whether the `pop` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes a value from the collection and returns it wrapped in an `Option`, or returns `None` if the collection is empty.
+  fn pop(&mut self) -> Option<V>;
+}
+
+impl<V, P: Pop<V>> Pop<V> for &mut P {
+  type Safety = <P as Pop<V>>::Safety;
+  fn pop(&mut self) -> Option<V> {
+    <P as Pop<V>>::pop(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Pop<V> for Vec<V> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Pop<V> for VecDeque<V> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    self.pop_front()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Pop<V> for BinaryHeap<V> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    BinaryHeap::pop(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Pop<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    self.pop_front()
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl Pop<SeV> for SeV {
+  type Safety = Safe;
+  /// Returns `None` if the value is not an array or the array is empty
+  fn pop(&mut self) -> Option<SeV> {
+    match self {
+      SeV::Array(a) => a.pop(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a> Pop<SBV<'a>> for SBV<'a> {
+  type Safety = Safe;
+  /// Returns `None` if the value is not an array or the array is empty
+  fn pop(&mut self) -> Option<SBV<'a>> {
+    match self {
+      SBV::Array(a) => a.pop(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Pop<SOV> for SOV {
+  type Safety = Safe;
+  /// Returns `None` if the value is not an array or the array is empty
+  fn pop(&mut self) -> Option<SOV> {
+    match self {
+      SOV::Array(a) => a.pop(),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+use slab::Slab;
+
+#[cfg(feature = "slab")]
+impl<V> Pop<V> for Slab<V> {
+  type Safety = Safe;
+  /// Removes the entry with the greatest key. No ordering should be assumed, since `Slab` reuses vacant keys on `push`.
+  fn pop(&mut self) -> Option<V> {
+    let k = self.iter().next_back()?.0;
+    Some(self.remove(k))
+  }
+}
+
+#[cfg(feature = "smallvec")]
+use smallvec::{Array, SmallVec};
+
+#[cfg(feature = "smallvec")]
+impl<V, A: Array<Item = V>> Pop<V> for SmallVec<A> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher> Pop<(K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  /// Removes the most recently inserted entry
+  fn pop(&mut self) -> Option<(K, V)> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + core::hash::Hash, S: core::hash::BuildHasher> Pop<K> for IndexSet<K, S> {
+  type Safety = Safe;
+  /// Removes the most recently inserted value
+  fn pop(&mut self) -> Option<K> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Pop<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    arrayvec::ArrayVec::pop(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Pop<V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    heapless::Vec::pop(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Pop<V> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    heapless::Deque::pop_front(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Pop<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<V> {
+    heapless::BinaryHeap::pop(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Pop<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<A::Item> {
+    tinyvec::ArrayVec::pop(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Pop<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn pop(&mut self) -> Option<A::Item> {
+    tinyvec::TinyVec::pop(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = vec![1, 2];
+    assert_eq!(<Vec<i32> as Pop<i32>>::pop(&mut v), Some(2));
+    let mut v = VecDeque::from([1, 2]);
+    assert_eq!(<VecDeque<i32> as Pop<i32>>::pop(&mut v), Some(1));
+    let mut h = BinaryHeap::from([1, 3, 2]);
+    assert_eq!(<BinaryHeap<i32> as Pop<i32>>::pop(&mut h), Some(3));
+    let mut l = LinkedList::from([1, 2]);
+    assert_eq!(<LinkedList<i32> as Pop<i32>>::pop(&mut l), Some(1));
+    assert_eq!(<LinkedList<i32> as Pop<i32>>::pop(&mut l), Some(2));
+    assert_eq!(<LinkedList<i32> as Pop<i32>>::pop(&mut l), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut a = SeV::Array(vec![SeV::Null]);
+    assert_eq!(<SeV as Pop<SeV>>::pop(&mut a), Some(SeV::Null));
+    assert_eq!(<SeV as Pop<SeV>>::pop(&mut SeV::Null), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = Slab::new();
+    s.insert(1);
+    s.insert(2);
+    assert_eq!(<Slab<i32> as Pop<i32>>::pop(&mut s), Some(2));
+    assert_eq!(<Slab<i32> as Pop<i32>>::pop(&mut s), Some(1));
+    assert_eq!(<Slab<i32> as Pop<i32>>::pop(&mut s), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = SmallVec::<[i32; 2]>::from_slice(&[1, 2]);
+    assert_eq!(<SmallVec<[i32; 2]> as Pop<i32>>::pop(&mut sv), Some(2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::from([(1, 0), (0, 1)]);
+    assert_eq!(
+      <IndexMap<i32, i32> as Pop<(i32, i32)>>::pop(&mut m),
+      Some((0, 1))
+    );
+    let mut s = IndexSet::from([1, 0]);
+    assert_eq!(<IndexSet<i32> as Pop<i32>>::pop(&mut s), Some(0));
+    assert_eq!(<IndexSet<i32> as Pop<i32>>::pop(&mut s), Some(1));
+    assert_eq!(<IndexSet<i32> as Pop<i32>>::pop(&mut s), None);
+  }
+}
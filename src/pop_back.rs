@@ -0,0 +1,136 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// Provides the ability to take a value out of the back of a collection.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{LinkedList, VecDeque};
+/// use collectivity::PopBack;
+///
+/// fn pop_back(col: &mut impl PopBack<i32>) -> Option<i32> {
+///   col.pop_back()
+/// }
+///
+/// assert_eq!(pop_back(&mut vec![0, 1]), Some(1));
+/// assert_eq!(pop_back(&mut VecDeque::from([0, 1])), Some(1));
+/// assert_eq!(pop_back(&mut LinkedList::new()), None);
+/// # }
+/// ```
+pub trait PopBack<V> {
+  /// Indicates whether the `pop_back` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes the last value of the collection and returns it wrapped in an `Option`, or returns `None` if the collection is empty.
+  fn pop_back(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopBack<V>> PopBack<V> for &mut P {
+  type Safety = <P as PopBack<V>>::Safety;
+  fn pop_back(&mut self) -> Option<V> {
+    <P as PopBack<V>>::pop_back(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PopBack<V> for Vec<V> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PopBack<V> for VecDeque<V> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    self.pop_back()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PopBack<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    self.pop_back()
+  }
+}
+
+#[cfg(feature = "smallvec")]
+use smallvec::{Array, SmallVec};
+
+#[cfg(feature = "smallvec")]
+impl<V, A: Array<Item = V>> PopBack<V> for SmallVec<A> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> PopBack<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    arrayvec::ArrayVec::pop(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> PopBack<V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    heapless::Vec::pop(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> PopBack<V> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<V> {
+    heapless::Deque::pop_back(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> PopBack<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<A::Item> {
+    tinyvec::ArrayVec::pop(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> PopBack<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn pop_back(&mut self) -> Option<A::Item> {
+    tinyvec::TinyVec::pop(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = vec![0, 1];
+    assert_eq!(<Vec<i32> as PopBack<i32>>::pop_back(&mut v), Some(1));
+    let mut v = VecDeque::from([0, 1]);
+    assert_eq!(<VecDeque<i32> as PopBack<i32>>::pop_back(&mut v), Some(1));
+    let mut l = LinkedList::from([0, 1]);
+    assert_eq!(<LinkedList<i32> as PopBack<i32>>::pop_back(&mut l), Some(1));
+    assert_eq!(<LinkedList<i32> as PopBack<i32>>::pop_back(&mut l), Some(0));
+    assert_eq!(<LinkedList<i32> as PopBack<i32>>::pop_back(&mut l), None);
+  }
+}
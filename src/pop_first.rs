@@ -0,0 +1,102 @@
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use crate::Sorted;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Provides the ability to take the entry with the smallest key out of an ordered collection.
+///
+/// Maps yield key-value pairs, while other collections yield their values. `BinaryHeap` only supports `PopLast`, as it only exposes its greatest value. `heapless::BinaryHeap` supports `PopFirst` when it is a min-heap.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeSet;
+/// use collectivity::{PopFirst, Sorted};
+///
+/// fn drain_sorted(col: &mut impl PopFirst<i32>) -> Vec<i32> {
+///   std::iter::from_fn(|| col.pop_first()).collect()
+/// }
+///
+/// assert_eq!(drain_sorted(&mut BTreeSet::from([2, 1, 3])), vec![1, 2, 3]);
+/// assert_eq!(drain_sorted(&mut Sorted::new(vec![2, 1, 3])), vec![1, 2, 3]);
+/// # }
+/// ```
+pub trait PopFirst<V> {
+  /// Indicates whether the `pop_first` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes the entry with the smallest key and returns it wrapped in an `Option`, or returns `None` if the collection is empty.
+  fn pop_first(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopFirst<V>> PopFirst<V> for &mut P {
+  type Safety = <P as PopFirst<V>>::Safety;
+  fn pop_first(&mut self) -> Option<V> {
+    <P as PopFirst<V>>::pop_first(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> PopFirst<(K, V)> for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  fn pop_first(&mut self) -> Option<(K, V)> {
+    alloc::collections::BTreeMap::pop_first(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> PopFirst<K> for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  fn pop_first(&mut self) -> Option<K> {
+    alloc::collections::BTreeSet::pop_first(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> PopFirst<V> for Sorted<Vec<V>> {
+  type Safety = Safe;
+  /// Shifts the remaining values, so it runs in linear time
+  fn pop_first(&mut self) -> Option<V> {
+    if self.is_empty() {
+      None
+    } else {
+      Some(self.inner_mut().remove(0))
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, const N: usize> PopFirst<V>
+  for heapless::BinaryHeap<V, heapless::binary_heap::Min, N>
+{
+  type Safety = Safe;
+  fn pop_first(&mut self) -> Option<V> {
+    heapless::BinaryHeap::pop(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut m = alloc::collections::BTreeMap::from([(1, 'a'), (2, 'b')]);
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, char> as PopFirst<_>>::pop_first(&mut m),
+      Some((1, 'a'))
+    );
+    assert_eq!(m.len(), 1);
+  }
+  #[test]
+  fn sorted() {
+    let mut s = Sorted::new(vec![2, 1, 3]);
+    assert_eq!(
+      <Sorted<Vec<i32>> as PopFirst<_>>::pop_first(&mut s),
+      Some(1)
+    );
+    assert_eq!(*s, vec![2, 3]);
+  }
+}
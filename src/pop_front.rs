@@ -0,0 +1,93 @@
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::collections::{LinkedList, VecDeque};
+
+/// Provides the ability to take a value out of the front of a double-ended collection.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{LinkedList, VecDeque};
+/// use collectivity::PopFront;
+///
+/// fn pop_front(col: &mut impl PopFront<i32>) -> Option<i32> {
+///   col.pop_front()
+/// }
+///
+/// assert_eq!(pop_front(&mut VecDeque::from([0, 1])), Some(0));
+/// assert_eq!(pop_front(&mut LinkedList::new()), None);
+/// # }
+/// ```
+pub trait PopFront<V> {
+  /// Indicates whether the `pop_front` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes the first value of the collection and returns it wrapped in an `Option`, or returns `None` if the collection is empty.
+  fn pop_front(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopFront<V>> PopFront<V> for &mut P {
+  type Safety = <P as PopFront<V>>::Safety;
+  fn pop_front(&mut self) -> Option<V> {
+    <P as PopFront<V>>::pop_front(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PopFront<V> for VecDeque<V> {
+  type Safety = Safe;
+  fn pop_front(&mut self) -> Option<V> {
+    self.pop_front()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PopFront<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn pop_front(&mut self) -> Option<V> {
+    self.pop_front()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> PopFront<V> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn pop_front(&mut self) -> Option<V> {
+    heapless::Deque::pop_front(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = VecDeque::from([0, 1]);
+    assert_eq!(<VecDeque<i32> as PopFront<i32>>::pop_front(&mut v), Some(0));
+    let mut l = LinkedList::from([0, 1]);
+    assert_eq!(
+      <LinkedList<i32> as PopFront<i32>>::pop_front(&mut l),
+      Some(0)
+    );
+    assert_eq!(
+      <LinkedList<i32> as PopFront<i32>>::pop_front(&mut l),
+      Some(1)
+    );
+    assert_eq!(<LinkedList<i32> as PopFront<i32>>::pop_front(&mut l), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut d = heapless::Deque::<i32, 2>::new();
+    d.push_back(1).unwrap();
+    d.push_back(2).unwrap();
+    assert_eq!(PopFront::pop_front(&mut d), Some(1));
+  }
+}
@@ -0,0 +1,104 @@
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use crate::Sorted;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Provides the ability to take the entry with the greatest key out of an ordered collection.
+///
+/// Maps yield key-value pairs, while other collections yield their values. `BinaryHeap` pops its greatest value.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeSet;
+/// use collectivity::{PopLast, Sorted};
+///
+/// fn drain_sorted(col: &mut impl PopLast<i32>) -> Vec<i32> {
+///   std::iter::from_fn(|| col.pop_last()).collect()
+/// }
+///
+/// assert_eq!(drain_sorted(&mut BTreeSet::from([2, 1, 3])), vec![3, 2, 1]);
+/// assert_eq!(drain_sorted(&mut Sorted::new(vec![2, 1, 3])), vec![3, 2, 1]);
+/// # }
+/// ```
+pub trait PopLast<V> {
+  /// Indicates whether the `pop_last` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes the entry with the greatest key and returns it wrapped in an `Option`, or returns `None` if the collection is empty.
+  fn pop_last(&mut self) -> Option<V>;
+}
+
+impl<V, P: PopLast<V>> PopLast<V> for &mut P {
+  type Safety = <P as PopLast<V>>::Safety;
+  fn pop_last(&mut self) -> Option<V> {
+    <P as PopLast<V>>::pop_last(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> PopLast<(K, V)> for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  fn pop_last(&mut self) -> Option<(K, V)> {
+    alloc::collections::BTreeMap::pop_last(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> PopLast<K> for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  fn pop_last(&mut self) -> Option<K> {
+    alloc::collections::BTreeSet::pop_last(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> PopLast<V> for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  fn pop_last(&mut self) -> Option<V> {
+    self.pop()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> PopLast<V> for Sorted<Vec<V>> {
+  type Safety = Safe;
+  fn pop_last(&mut self) -> Option<V> {
+    self.inner_mut().pop()
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, const N: usize> PopLast<V> for heapless::BinaryHeap<V, heapless::binary_heap::Max, N> {
+  type Safety = Safe;
+  fn pop_last(&mut self) -> Option<V> {
+    heapless::BinaryHeap::pop(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut m = alloc::collections::BTreeMap::from([(1, 'a'), (2, 'b')]);
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, char> as PopLast<_>>::pop_last(&mut m),
+      Some((2, 'b'))
+    );
+    assert_eq!(m.len(), 1);
+    assert_eq!(
+      <alloc::collections::BinaryHeap<i32> as PopLast<_>>::pop_last(&mut [1, 3, 2].into()),
+      Some(3)
+    );
+  }
+  #[test]
+  fn sorted() {
+    let mut s = Sorted::new(vec![2, 1, 3]);
+    assert_eq!(<Sorted<Vec<i32>> as PopLast<_>>::pop_last(&mut s), Some(3));
+    assert_eq!(*s, vec![1, 2]);
+  }
+}
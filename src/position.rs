@@ -0,0 +1,16 @@
+/// Addresses an entry of an insertion-ordered collection by its position rather than by its key.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "indexmap")]
+/// # {
+/// use collectivity::{Get, Position};
+/// use indexmap::IndexMap;
+///
+/// let m = IndexMap::from([("B", 2), ("A", 1)]);
+/// assert_eq!(m.get(&"A"), Some(&1));
+/// assert_eq!(<IndexMap<_, _> as Get<Position>>::get(&m, Position(0)), Some((&"B", &2)));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position(pub usize);
@@ -1,6 +1,20 @@
-use crate::{Safe, SafetyMarker, Unsafe};
-#[cfg(feature = "std")]
-use std::collections::{BinaryHeap, LinkedList, VecDeque};
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::Logarithmic;
+#[cfg(feature = "alloc")]
+use crate::{AmortizedConstant, Safe};
+use crate::{ComplexityMarker, SafetyMarker};
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::{Constant, Unsafe};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
 
 /// Provides the ability to add a specified value to a collection.
 ///
@@ -8,6 +22,7 @@ use std::collections::{BinaryHeap, LinkedList, VecDeque};
 ///
 /// ## Examples
 /// ```
+/// # #[cfg(feature = "alloc")] {
 /// use std::collections::LinkedList;
 /// use collectivity::{Get, Push};
 ///
@@ -25,10 +40,13 @@ use std::collections::{BinaryHeap, LinkedList, VecDeque};
 /// let mut l = LinkedList::new();
 /// push(&mut l, 0);
 /// assert_eq!(l.get(0), Some(&0));
+/// # }
 /// ```
 pub trait Push<V> {
   /// Indicates whether the `push` method may panic in a particular implementation.
-  type Safety: SafetyMarker = Unsafe;
+  type Safety: SafetyMarker;
+  /// Indicates the time complexity of the `push` method in a particular implementation.
+  type Complexity: ComplexityMarker;
   /// Adds the provided value to the collection.
   ///
   /// # Panics
@@ -37,35 +55,44 @@ pub trait Push<V> {
   fn push(&mut self, v: V);
 }
 
-impl<'p, V, P: Push<V>> Push<V> for &'p mut P {
-  fn push<'a>(&'a mut self, v: V) {
+impl<V, P: Push<V>> Push<V> for &mut P {
+  type Safety = <P as Push<V>>::Safety;
+  type Complexity = <P as Push<V>>::Complexity;
+  fn push(&mut self, v: V) {
     <P as Push<V>>::push(self, v)
   }
 }
+#[cfg(feature = "alloc")]
 impl<V> Push<V> for Vec<V> {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: V) {
     self.push(v)
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> Push<V> for VecDeque<V> {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: V) {
     VecDeque::push_back(self, v)
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V: Ord> Push<V> for BinaryHeap<V> {
   type Safety = Safe;
+  type Complexity = Logarithmic;
   fn push(&mut self, v: V) {
     BinaryHeap::push(self, v)
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> Push<V> for LinkedList<V> {
   type Safety = Safe;
+  type Complexity = Constant;
   fn push(&mut self, v: V) {
     self.push_back(v)
   }
@@ -76,6 +103,8 @@ use serde_json::Value as SeV;
 
 #[cfg(feature = "serde_json")]
 impl Push<SeV> for SeV {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: SeV) {
     match self {
       SeV::Array(o) => {
@@ -91,6 +120,8 @@ use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
 
 #[cfg(feature = "simd-json")]
 impl<'a> Push<SBV<'a>> for SBV<'a> {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: SBV<'a>) {
     match self {
       SBV::Array(a) => {
@@ -103,6 +134,8 @@ impl<'a> Push<SBV<'a>> for SBV<'a> {
 
 #[cfg(feature = "simd-json")]
 impl Push<SOV> for SOV {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: SOV) {
     match self {
       SOV::Array(a) => {
@@ -118,6 +151,8 @@ use slab::Slab;
 
 #[cfg(feature = "slab")]
 impl<V> Push<V> for Slab<V> {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: V) {
     self.insert(v);
   }
@@ -128,7 +163,182 @@ use smallvec::{Array, SmallVec};
 
 #[cfg(feature = "smallvec")]
 impl<V, A: Array<Item = V>> Push<V> for SmallVec<A> {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
   fn push(&mut self, v: V) {
     self.push(v)
   }
 }
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher> Push<(K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  /// Appends a new key, or replaces the value of an existing key in place
+  fn push(&mut self, (k, v): (K, V)) {
+    self.insert(k, v);
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + core::hash::Hash, S: core::hash::BuildHasher> Push<K> for IndexSet<K, S> {
+  type Safety = Safe;
+  type Complexity = AmortizedConstant;
+  /// Appends a new value, leaving an existing one in place
+  fn push(&mut self, k: K) {
+    self.insert(k);
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Push<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  type Complexity = Constant;
+  /// Panics if the collection is full
+  fn push(&mut self, v: V) {
+    arrayvec::ArrayVec::push(self, v)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Push<V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  type Complexity = Constant;
+  /// Panics if the collection is full
+  fn push(&mut self, v: V) {
+    if heapless::Vec::push(self, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Push<V> for heapless::Deque<V, N> {
+  type Safety = Unsafe;
+  type Complexity = Constant;
+  /// Panics if the collection is full
+  fn push(&mut self, v: V) {
+    if heapless::Deque::push_back(self, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Push<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Unsafe;
+  type Complexity = Logarithmic;
+  /// Panics if the collection is full
+  fn push(&mut self, v: V) {
+    if heapless::BinaryHeap::push(self, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher, const N: usize> Push<(K, V)>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Unsafe;
+  type Complexity = Constant;
+  /// Appends a new key, or replaces the value of an existing key in place. Panics if the key is new and the map is full
+  fn push(&mut self, (k, v): (K, V)) {
+    if heapless::IndexMap::insert(self, k, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Push<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  type Complexity = Constant;
+  /// Panics if the collection is full
+  fn push(&mut self, v: A::Item) {
+    tinyvec::ArrayVec::push(self, v)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Push<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  type Complexity = AmortizedConstant;
+  fn push(&mut self, v: A::Item) {
+    tinyvec::TinyVec::push(self, v)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::new();
+    <IndexMap<i32, i32> as Push<(i32, i32)>>::push(&mut m, (1, 0));
+    <IndexMap<i32, i32> as Push<(i32, i32)>>::push(&mut m, (0, 1));
+    assert_eq!(m.last(), Some((&0, &1)));
+    let mut s = IndexSet::new();
+    <IndexSet<i32> as Push<i32>>::push(&mut s, 1);
+    <IndexSet<i32> as Push<i32>>::push(&mut s, 0);
+    <IndexSet<i32> as Push<i32>>::push(&mut s, 1);
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&1, &0]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  #[should_panic]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 1>::new();
+    <arrayvec::ArrayVec<i32, 1> as Push<i32>>::push(&mut v, 1);
+    <arrayvec::ArrayVec<i32, 1> as Push<i32>>::push(&mut v, 2);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Max, 2>::new();
+    <heapless::BinaryHeap<i32, heapless::binary_heap::Max, 2> as Push<i32>>::push(&mut h, 1);
+    <heapless::BinaryHeap<i32, heapless::binary_heap::Max, 2> as Push<i32>>::push(&mut h, 2);
+    assert_eq!(h.peek(), Some(&2));
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::new();
+    Push::push(&mut m, (1, 0));
+    Push::push(&mut m, (1, 1));
+    assert_eq!(m.iter().collect::<Vec<_>>(), [(&1, &1)]);
+  }
+  #[test]
+  #[should_panic]
+  fn heapless_full() {
+    let mut v = heapless::Vec::<i32, 1>::new();
+    <heapless::Vec<i32, 1> as Push<i32>>::push(&mut v, 1);
+    <heapless::Vec<i32, 1> as Push<i32>>::push(&mut v, 2);
+  }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::TinyVec::<[i32; 1]>::new();
+    <tinyvec::TinyVec<[i32; 1]> as Push<i32>>::push(&mut v, 1);
+    <tinyvec::TinyVec<[i32; 1]> as Push<i32>>::push(&mut v, 2);
+    assert!(v.is_heap());
+    assert_eq!(v.as_slice(), &[1, 2]);
+  }
+}
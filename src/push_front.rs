@@ -0,0 +1,104 @@
+#[cfg(feature = "alloc")]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::Unsafe;
+#[cfg(feature = "alloc")]
+use alloc::collections::{LinkedList, VecDeque};
+
+/// Provides the ability to add a specified value to the front of a double-ended collection.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::{LinkedList, VecDeque};
+/// use collectivity::PushFront;
+///
+/// fn push_front(
+///   col: &mut impl PushFront<i32>,
+///   v: i32
+/// ) {
+///   col.push_front(v);
+/// }
+///
+/// let mut v = VecDeque::from([1]);
+/// push_front(&mut v, 0);
+/// assert_eq!(v, VecDeque::from([0, 1]));
+///
+/// let mut l = LinkedList::from([1]);
+/// push_front(&mut l, 0);
+/// assert_eq!(l, LinkedList::from([0, 1]));
+/// # }
+/// ```
+pub trait PushFront<V> {
+  /// Indicates whether the `push_front` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Adds the provided value to the front of the collection.
+  ///
+  /// # Panics
+  ///
+  /// May panic, typically when the maximum collection size is exceeded.
+  fn push_front(&mut self, v: V);
+}
+
+impl<V, P: PushFront<V>> PushFront<V> for &mut P {
+  type Safety = <P as PushFront<V>>::Safety;
+  fn push_front(&mut self, v: V) {
+    <P as PushFront<V>>::push_front(self, v)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PushFront<V> for VecDeque<V> {
+  type Safety = Unsafe;
+  fn push_front(&mut self, v: V) {
+    self.push_front(v)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> PushFront<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn push_front(&mut self, v: V) {
+    self.push_front(v)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> PushFront<V> for heapless::Deque<V, N> {
+  type Safety = Unsafe;
+  /// Panics if the collection is full
+  fn push_front(&mut self, v: V) {
+    if heapless::Deque::push_front(self, v).is_err() {
+      panic!("Capacity exceeded")
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = VecDeque::from([1]);
+    <VecDeque<i32> as PushFront<i32>>::push_front(&mut v, 0);
+    assert_eq!(v, VecDeque::from([0, 1]));
+    let mut l = LinkedList::from([1]);
+    <LinkedList<i32> as PushFront<i32>>::push_front(&mut l, 0);
+    assert_eq!(l, LinkedList::from([0, 1]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut d = heapless::Deque::<i32, 2>::new();
+    PushFront::push_front(&mut d, 2);
+    PushFront::push_front(&mut d, 1);
+    assert_eq!(d.iter().collect::<Vec<_>>(), [&1, &2]);
+  }
+}
@@ -0,0 +1,140 @@
+#[cfg(feature = "alloc")]
+use crate::Unsafe;
+use crate::{Safe, SafetyMarker, Sorted};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Bound, RangeBounds};
+
+/// Provides the ability to iterate over the entries of an ordered collection whose keys fall within a range.
+///
+/// Maps yield key-value pairs, while other collections yield their values. Entries are yielded in key order.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeSet;
+/// use collectivity::{Range, Sorted};
+///
+/// fn window<'a, C: Range<i32, Range<'a> = impl Iterator<Item = &'a i32>>>(col: &'a C) -> Vec<i32> {
+///   col.range(2..4).copied().collect()
+/// }
+///
+/// assert_eq!(window(&BTreeSet::from([1, 2, 3, 4])), vec![2, 3]);
+/// assert_eq!(window(&Sorted::new([4, 3, 2, 1])), vec![2, 3]);
+/// # }
+/// ```
+pub trait Range<K> {
+  /// Indicates whether the `range` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `range`.
+  type Range<'a>: Iterator
+  where
+    Self: 'a;
+  /// Returns an iterator over the entries whose keys fall within `r`.
+  fn range<'a, R: RangeBounds<K>>(&'a self, r: R) -> Self::Range<'a>;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Range<K> for alloc::collections::BTreeMap<K, V> {
+  type Safety = Unsafe;
+  type Range<'a>
+    = alloc::collections::btree_map::Range<'a, K, V>
+  where
+    Self: 'a;
+
+  /// Panics if the range start is greater than its end, or if both bounds are equal and excluded
+  fn range<'a, R: RangeBounds<K>>(&'a self, r: R) -> Self::Range<'a> {
+    alloc::collections::BTreeMap::range(self, r)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Range<K> for alloc::collections::BTreeSet<K> {
+  type Safety = Unsafe;
+  type Range<'a>
+    = alloc::collections::btree_set::Range<'a, K>
+  where
+    Self: 'a;
+
+  /// Panics if the range start is greater than its end, or if both bounds are equal and excluded
+  fn range<'a, R: RangeBounds<K>>(&'a self, r: R) -> Self::Range<'a> {
+    alloc::collections::BTreeSet::range(self, r)
+  }
+}
+
+fn sorted_range<V: Ord, R: RangeBounds<V>>(s: &[V], r: R) -> core::slice::Iter<'_, V> {
+  let start = match r.start_bound() {
+    Bound::Included(k) => s.partition_point(|v| v < k),
+    Bound::Excluded(k) => s.partition_point(|v| v <= k),
+    Bound::Unbounded => 0,
+  };
+  let end = match r.end_bound() {
+    Bound::Included(k) => s.partition_point(|v| v <= k),
+    Bound::Excluded(k) => s.partition_point(|v| v < k),
+    Bound::Unbounded => s.len(),
+  };
+  s[start..end.max(start)].iter()
+}
+
+impl<V: Ord, const N: usize> Range<V> for Sorted<[V; N]> {
+  type Safety = Safe;
+  type Range<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  /// Yields nothing if the range is empty
+  fn range<'a, R: RangeBounds<V>>(&'a self, r: R) -> Self::Range<'a> {
+    sorted_range(&self[..], r)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Range<V> for Sorted<Vec<V>> {
+  type Safety = Safe;
+  type Range<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  /// Yields nothing if the range is empty
+  fn range<'a, R: RangeBounds<V>>(&'a self, r: R) -> Self::Range<'a> {
+    sorted_range(self, r)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let m = alloc::collections::BTreeMap::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, char> as Range<i32>>::range(&m, 2..).collect::<Vec<_>>(),
+      vec![(&2, &'b'), (&3, &'c')]
+    );
+  }
+  #[test]
+  fn sorted() {
+    let s = Sorted::new(vec![1, 2, 2, 3, 5]);
+    let range = |r: (Bound<i32>, Bound<i32>)| {
+      <Sorted<Vec<i32>> as Range<i32>>::range(&s, r)
+        .copied()
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(
+      range((Bound::Included(2), Bound::Excluded(5))),
+      vec![2, 2, 3]
+    );
+    assert_eq!(range((Bound::Excluded(2), Bound::Included(5))), vec![3, 5]);
+    assert_eq!(
+      range((Bound::Unbounded, Bound::Excluded(0))),
+      Vec::<i32>::new()
+    );
+    assert_eq!(
+      range((Bound::Included(4), Bound::Excluded(1))),
+      Vec::<i32>::new()
+    );
+  }
+}
@@ -1,14 +1,34 @@
-use crate::{Safe, SafetyMarker, Unsafe};
-#[cfg(feature = "std")]
-use std::{
-  collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
-  hash::Hash,
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use crate::Constant;
+#[cfg(feature = "alloc")]
+use crate::Logarithmic;
+use crate::{ComplexityMarker, KeyStabilityMarker, SafetyMarker};
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::{Linear, Shifting, Unsafe};
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use crate::{Safe, Stable};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  vec::Vec,
 };
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use core::borrow::Borrow;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// Provides the ability to remove a value by key, potentially moving other items within the collection.
 ///
 /// # Examples
 /// ```
+/// # #[cfg(feature = "std")] {
 /// use std::collections::HashMap;
 /// use collectivity::Remove;
 ///
@@ -22,10 +42,17 @@ use std::{
 /// let mut v = HashMap::from([("A", 1), ("B", 2), ("C", 3)]);
 /// v.remove("B");
 /// assert_eq!(v, HashMap::from([("A", 1), ("C", 3)]));
+/// let mut m = HashMap::from([(String::from("A"), 1)]);
+/// assert_eq!(remove(&mut m, "A"), Some(1));
+/// # }
 /// ```
 pub trait Remove<K, V> {
   /// Indicates whether the `remove` method may panic in a particular implementation.
   type Safety: SafetyMarker;
+  /// Indicates the time complexity of the `remove` method in a particular implementation.
+  type Complexity: ComplexityMarker;
+  /// Indicates whether the `remove` method may change the keys of other entries in a particular implementation.
+  type KeyStability: KeyStabilityMarker;
   /// If there's a value at a specified key, the method removes it and returns it wrapped in an `Option`. Otherwise, it returns `None` without affecting the collection.
   ///
   /// # Panics
@@ -34,59 +61,64 @@ pub trait Remove<K, V> {
   fn remove(&mut self, k: K) -> Option<V>;
 }
 
-impl<'r, K, V, R: Remove<K, V>> Remove<K, V> for &'r mut R {
+impl<K, V, R: Remove<K, V>> Remove<K, V> for &mut R {
   type Safety = <R as Remove<K, V>>::Safety;
-  fn remove<'a>(&'a mut self, k: K) -> Option<V> {
+  type Complexity = <R as Remove<K, V>>::Complexity;
+  type KeyStability = <R as Remove<K, V>>::KeyStability;
+  fn remove(&mut self, k: K) -> Option<V> {
     <R as Remove<K, V>>::remove(self, k)
   }
 }
 
+#[cfg(feature = "alloc")]
 impl<V> Remove<usize, V> for Vec<V> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<V> {
     Some(self.remove(k))
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> Remove<usize, V> for VecDeque<V> {
   type Safety = Safe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<V> {
     VecDeque::remove(self, k)
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> Remove<usize, V> for LinkedList<V> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<V> {
     let mut rest = self.split_off(k);
-    let v = self.pop_front();
+    let v = rest.pop_front();
     self.append(&mut rest);
     v
   }
 }
 
-#[cfg(feature = "std")]
-impl<'k, K: Ord, V> Remove<&'k K, V> for BTreeMap<K, V> {
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord, V> Remove<&'k Q, V> for BTreeMap<K, V> {
   type Safety = Safe;
-  fn remove(&mut self, k: &'k K) -> Option<V> {
+  type Complexity = Logarithmic;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<V> {
     self.remove(k)
   }
 }
 
-#[cfg(feature = "std")]
-impl<K: Ord, V> Remove<K, V> for BTreeMap<K, V> {
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord> Remove<&'k Q, ()> for BTreeSet<K> {
   type Safety = Safe;
-  fn remove(&mut self, k: K) -> Option<V> {
-    self.remove(&k)
-  }
-}
-
-#[cfg(feature = "std")]
-impl<'k, K: Ord> Remove<&'k K, ()> for BTreeSet<K> {
-  type Safety = Safe;
-  fn remove(&mut self, k: &'k K) -> Option<()> {
+  type Complexity = Logarithmic;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<()> {
     if self.remove(k) {
       Some(())
     } else {
@@ -96,37 +128,25 @@ impl<'k, K: Ord> Remove<&'k K, ()> for BTreeSet<K> {
 }
 
 #[cfg(feature = "std")]
-impl<K: Ord> Remove<K, ()> for BTreeSet<K> {
-  type Safety = Safe;
-  fn remove(&mut self, k: K) -> Option<()> {
-    if self.remove(&k) {
-      Some(())
-    } else {
-      None
-    }
-  }
-}
-
-#[cfg(feature = "std")]
-impl<'k, K: Eq + Hash, V> Remove<&'k K, V> for HashMap<K, V> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Remove<&'k Q, V>
+  for HashMap<K, V, S>
+{
   type Safety = Safe;
-  fn remove(&mut self, k: &'k K) -> Option<V> {
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<V> {
     self.remove(k)
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash, V> Remove<K, V> for HashMap<K, V> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Remove<&'k Q, ()>
+  for HashSet<K, S>
+{
   type Safety = Safe;
-  fn remove(&mut self, k: K) -> Option<V> {
-    self.remove(&k)
-  }
-}
-
-#[cfg(feature = "std")]
-impl<'k, K: Eq + Hash> Remove<&'k K, ()> for HashSet<K> {
-  type Safety = Safe;
-  fn remove(&mut self, k: &'k K) -> Option<()> {
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<()> {
     if self.remove(k) {
       Some(())
     } else {
@@ -135,59 +155,41 @@ impl<'k, K: Eq + Hash> Remove<&'k K, ()> for HashSet<K> {
   }
 }
 
-#[cfg(feature = "std")]
-impl<K: Eq + Hash> Remove<K, ()> for HashSet<K> {
-  type Safety = Safe;
-  fn remove(&mut self, k: K) -> Option<()> {
-    if self.remove(&k) {
-      Some(())
-    } else {
-      None
-    }
-  }
-}
-
 #[cfg(feature = "dashmap")]
 use dashmap::{DashMap, DashSet};
 
 #[cfg(feature = "dashmap")]
-impl<'k, K: Eq + Hash, V> Remove<&'k K, V> for DashMap<K, V> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher + Clone>
+  Remove<&'k Q, V> for DashMap<K, V, S>
+{
   type Safety = Safe;
-  fn remove(&mut self, k: &'k K) -> Option<V> {
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<V> {
     DashMap::remove(self, k).map(|v| v.1)
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash, V> Remove<K, V> for DashMap<K, V> {
-  type Safety = Safe;
-  fn remove(&mut self, k: K) -> Option<V> {
-    DashMap::remove(self, &k).map(|v| v.1)
-  }
-}
-
-#[cfg(feature = "dashmap")]
-impl<'k, K: Eq + Hash> Remove<&'k K, K> for DashSet<K> {
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher + Clone> Remove<&'k Q, K>
+  for DashSet<K, S>
+{
   type Safety = Safe;
-  fn remove(&mut self, k: &'k K) -> Option<K> {
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<K> {
     DashSet::remove(self, k)
   }
 }
 
-#[cfg(feature = "dashmap")]
-impl<K: Eq + Hash> Remove<K, K> for DashSet<K> {
-  type Safety = Safe;
-  fn remove(&mut self, k: K) -> Option<K> {
-    DashSet::remove(self, &k)
-  }
-}
-
 #[cfg(feature = "serde_json")]
 use serde_json::Value as SeV;
 
 #[cfg(feature = "serde_json")]
 impl<'k> Remove<&'k str, SeV> for SeV {
   type Safety = Unsafe;
+  type Complexity = Logarithmic;
+  type KeyStability = Stable;
   fn remove(&mut self, k: &'k str) -> Option<SeV> {
     match self {
       SeV::Object(o) => o.remove(k),
@@ -199,6 +201,8 @@ impl<'k> Remove<&'k str, SeV> for SeV {
 #[cfg(feature = "serde_json")]
 impl Remove<usize, SeV> for SeV {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<SeV> {
     match self {
       SeV::Array(a) => Some(a.remove(k)),
@@ -213,6 +217,8 @@ use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
 #[cfg(feature = "simd-json")]
 impl<'k, 'a> Remove<&'k str, SBV<'a>> for SBV<'a> {
   type Safety = Unsafe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
   fn remove(&mut self, k: &'k str) -> Option<SBV<'a>> {
     match self {
       SBV::Object(o) => o.remove(k),
@@ -224,6 +230,8 @@ impl<'k, 'a> Remove<&'k str, SBV<'a>> for SBV<'a> {
 #[cfg(feature = "simd-json")]
 impl<'a> Remove<usize, SBV<'a>> for SBV<'a> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<SBV<'a>> {
     match self {
       SBV::Array(a) => Some(a.remove(k)),
@@ -235,6 +243,8 @@ impl<'a> Remove<usize, SBV<'a>> for SBV<'a> {
 #[cfg(feature = "simd-json")]
 impl<'k> Remove<&'k str, SOV> for SOV {
   type Safety = Unsafe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
   fn remove(&mut self, k: &'k str) -> Option<SOV> {
     match self {
       SOV::Object(o) => o.remove(k),
@@ -246,6 +256,8 @@ impl<'k> Remove<&'k str, SOV> for SOV {
 #[cfg(feature = "simd-json")]
 impl Remove<usize, SOV> for SOV {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<SOV> {
     match self {
       SOV::Array(a) => Some(a.remove(k)),
@@ -260,6 +272,8 @@ use slab::Slab;
 #[cfg(feature = "slab")]
 impl<V> Remove<usize, V> for Slab<V> {
   type Safety = Unsafe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
   fn remove(&mut self, k: usize) -> Option<V> {
     Some(self.remove(k))
   }
@@ -271,7 +285,301 @@ use smallvec::{Array, SmallVec};
 #[cfg(feature = "smallvec")]
 impl<V, A: Array<Item = V>> Remove<usize, V> for SmallVec<A> {
   type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
   fn remove(&mut self, k: usize) -> Option<V> {
     Some(self.remove(k))
   }
 }
+
+#[cfg(feature = "indexmap")]
+use crate::{Position, Swap};
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Remove<&'k Q, V>
+  for IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Linear;
+  type KeyStability = Stable;
+  /// Shifts the following entries to preserve the insertion order
+  fn remove(&mut self, k: &'k Q) -> Option<V> {
+    self.shift_remove(k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Remove<Swap<&'k Q>, V>
+  for IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: Swap<&'k Q>) -> Option<V> {
+    self.swap_remove(k.0)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Remove<Position, (K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  /// Shifts the following entries to preserve the insertion order
+  fn remove(&mut self, k: Position) -> Option<(K, V)> {
+    self.shift_remove_index(k.0)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Remove<Swap<Position>, (K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Shifting;
+  fn remove(&mut self, k: Swap<Position>) -> Option<(K, V)> {
+    self.swap_remove_index(k.0 .0)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Remove<&'k Q, ()>
+  for IndexSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Linear;
+  type KeyStability = Stable;
+  /// Shifts the following values to preserve the insertion order
+  fn remove(&mut self, k: &'k Q) -> Option<()> {
+    self.shift_remove(k).then_some(())
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Remove<Swap<&'k Q>, ()>
+  for IndexSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: Swap<&'k Q>) -> Option<()> {
+    self.swap_remove(k.0).then_some(())
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Remove<Position, K> for IndexSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  /// Shifts the following values to preserve the insertion order
+  fn remove(&mut self, k: Position) -> Option<K> {
+    self.shift_remove_index(k.0)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Remove<Swap<Position>, K> for IndexSet<K, S> {
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Shifting;
+  fn remove(&mut self, k: Swap<Position>) -> Option<K> {
+    self.swap_remove_index(k.0 .0)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> Remove<&'k Q, V>
+  for hashbrown::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<V> {
+    hashbrown::HashMap::remove(self, k)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> Remove<&'k Q, ()>
+  for hashbrown::HashSet<K, S>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  fn remove(&mut self, k: &'k Q) -> Option<()> {
+    if hashbrown::HashSet::remove(self, k) {
+      Some(())
+    } else {
+      None
+    }
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Remove<usize, V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  fn remove(&mut self, k: usize) -> Option<V> {
+    Some(arrayvec::ArrayVec::remove(self, k))
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Remove<usize, V> for heapless::Vec<V, N> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  fn remove(&mut self, k: usize) -> Option<V> {
+    Some(heapless::Vec::remove(self, k))
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher, const N: usize>
+  Remove<&'k Q, V> for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  type Complexity = Constant;
+  type KeyStability = Stable;
+  /// Moves the last entry into the position of the removed one
+  fn remove(&mut self, k: &'k Q) -> Option<V> {
+    heapless::IndexMap::remove(self, k)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Remove<usize, A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  fn remove(&mut self, k: usize) -> Option<A::Item> {
+    Some(tinyvec::ArrayVec::remove(self, k))
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Remove<usize, A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  type Complexity = Linear;
+  type KeyStability = Shifting;
+  fn remove(&mut self, k: usize) -> Option<A::Item> {
+    Some(tinyvec::TinyVec::remove(self, k))
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  use std::hash::{BuildHasherDefault, DefaultHasher};
+  #[test]
+  fn custom_hasher() {
+    let mut m = HashMap::<_, _, BuildHasherDefault<DefaultHasher>>::default();
+    m.insert(String::from("A"), 1);
+    assert_eq!(Remove::remove(&mut m, "A"), Some(1));
+    assert_eq!(Remove::remove(&mut m, "A"), None);
+    let mut s = HashSet::<_, BuildHasherDefault<DefaultHasher>>::default();
+    s.insert(0);
+    assert_eq!(Remove::remove(&mut s, &0), Some(()));
+  }
+  #[test]
+  fn linked_list() {
+    let mut l = LinkedList::from([0, 1, 2]);
+    assert_eq!(Remove::remove(&mut l, 1), Some(1));
+    assert_eq!(Remove::remove(&mut l, 0), Some(0));
+    assert_eq!(l, LinkedList::from([2]));
+    assert_eq!(Remove::remove(&mut l, 1), None);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn index_map() {
+    let mut m = IndexMap::from([("A", 1), ("B", 2), ("C", 3), ("D", 4)]);
+    assert_eq!(Remove::remove(&mut m, &"A"), Some(1));
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&"B", &"C", &"D"]);
+    assert_eq!(Remove::remove(&mut m, Swap(&"B")), Some(2));
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&"D", &"C"]);
+    assert_eq!(Remove::remove(&mut m, Swap(&"B")), None);
+    m.insert("E", 5);
+    assert_eq!(
+      <IndexMap<&str, i32> as Remove<Position, _>>::remove(&mut m, Position(0)),
+      Some(("D", 4))
+    );
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&"C", &"E"]);
+    m.insert("F", 6);
+    assert_eq!(
+      <IndexMap<&str, i32> as Remove<Swap<Position>, _>>::remove(&mut m, Swap(Position(0))),
+      Some(("C", 3))
+    );
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&"F", &"E"]);
+    assert_eq!(
+      <IndexMap<&str, i32> as Remove<Position, _>>::remove(&mut m, Position(2)),
+      None
+    );
+  }
+  #[test]
+  fn index_set() {
+    let mut s = IndexSet::from([1, 2, 3, 4]);
+    assert_eq!(Remove::remove(&mut s, &1), Some(()));
+    assert_eq!(Remove::remove(&mut s, &1), None);
+    assert_eq!(Remove::remove(&mut s, Swap(&2)), Some(()));
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&4, &3]);
+    assert_eq!(
+      <IndexSet<i32> as Remove<Position, _>>::remove(&mut s, Position(0)),
+      Some(4)
+    );
+    s.extend([5, 6]);
+    assert_eq!(
+      <IndexSet<i32> as Remove<Swap<Position>, _>>::remove(&mut s, Swap(Position(0))),
+      Some(3)
+    );
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&6, &5]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(String::from("A"), 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<String, i32> as Remove<&str, i32>>::remove(&mut m, "A"),
+      Some(1)
+    );
+    assert_eq!(
+      <hashbrown::HashMap<String, i32> as Remove<&str, i32>>::remove(&mut m, "A"),
+      None
+    );
+    let mut s = hashbrown::HashSet::from([0]);
+    assert_eq!(
+      <hashbrown::HashSet<i32> as Remove<&i32, ()>>::remove(&mut s, &0),
+      Some(())
+    );
+    assert_eq!(
+      <hashbrown::HashSet<i32> as Remove<&i32, ()>>::remove(&mut s, &0),
+      None
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut m = heapless::FnvIndexMap::<i32, i32, 4>::from_iter([(1, 1), (2, 2), (3, 3)]);
+    assert_eq!(Remove::remove(&mut m, &1), Some(1));
+    assert_eq!(Remove::remove(&mut m, &1), None);
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&3, &2]);
+  }
+}
@@ -0,0 +1,395 @@
+#[cfg(feature = "alloc")]
+use crate::Unsafe;
+use crate::{Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to control the allocated capacity of a collection.
+///
+/// The methods are no-ops for collections whose capacity cannot be controlled, such as node-based collections and fixed-size arrays.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::{BTreeMap, HashMap};
+/// use collectivity::{Capacity, Reserve};
+///
+/// fn prepare(col: &mut (impl Reserve + Capacity)) -> usize {
+///   col.reserve(10);
+///   col.capacity()
+/// }
+///
+/// assert!(prepare(&mut HashMap::<(), ()>::new()) >= 10);
+/// assert_eq!(prepare(&mut BTreeMap::<(), ()>::new()), 0);
+/// # }
+/// ```
+pub trait Reserve {
+  /// Indicates whether the `reserve`, `reserve_exact` and `shrink_to_fit` methods may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Reserves capacity for at least `additional` more entries. Panics if the new capacity overflows.
+  fn reserve(&mut self, additional: usize);
+  /// Reserves capacity for exactly `additional` more entries, where the collection supports it. Panics if the new capacity overflows.
+  fn reserve_exact(&mut self, additional: usize);
+  /// Shrinks the capacity of the collection as much as possible.
+  fn shrink_to_fit(&mut self);
+}
+
+impl<R: Reserve> Reserve for &mut R {
+  type Safety = <R as Reserve>::Safety;
+  fn reserve(&mut self, additional: usize) {
+    <R as Reserve>::reserve(self, additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    <R as Reserve>::reserve_exact(self, additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    <R as Reserve>::shrink_to_fit(self)
+  }
+}
+
+impl<V, const N: usize> Reserve for [V; N] {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Reserve for Vec<V> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Reserve for alloc::collections::VecDeque<V> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Reserve for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Reserve for alloc::collections::BinaryHeap<V> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Reserve for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Reserve for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Reserve for std::collections::HashMap<K, V, S> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  /// Same as `reserve`
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> Reserve for std::collections::HashSet<K, S> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  /// Same as `reserve`
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Reserve for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  /// No-op, `DashMap` can only be given capacity upon construction
+  fn reserve(&mut self, _: usize) {}
+  /// No-op, `DashMap` can only be given capacity upon construction
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {
+    dashmap::DashMap::shrink_to_fit(self)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> Reserve for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  /// No-op, `DashSet` can only be given capacity upon construction
+  fn reserve(&mut self, _: usize) {}
+  /// No-op, `DashSet` can only be given capacity upon construction
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {
+    dashmap::DashSet::shrink_to_fit(self)
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Reserve for slab::Slab<V> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Reserve for smallvec::SmallVec<A> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Reserve for indexmap::IndexMap<K, V, S> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Reserve for indexmap::IndexSet<K, S> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    self.reserve(additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    self.reserve_exact(additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    self.shrink_to_fit()
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Reserve for hashbrown::HashMap<K, V, S> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    hashbrown::HashMap::reserve(self, additional)
+  }
+  /// Same as `reserve`
+  fn reserve_exact(&mut self, additional: usize) {
+    hashbrown::HashMap::reserve(self, additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    hashbrown::HashMap::shrink_to_fit(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Reserve for hashbrown::HashSet<K, S> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    hashbrown::HashSet::reserve(self, additional)
+  }
+  /// Same as `reserve`
+  fn reserve_exact(&mut self, additional: usize) {
+    hashbrown::HashSet::reserve(self, additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    hashbrown::HashSet::shrink_to_fit(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Reserve for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Reserve for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Reserve for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Reserve
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Reserve for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Reserve for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn reserve(&mut self, _: usize) {}
+  fn reserve_exact(&mut self, _: usize) {}
+  fn shrink_to_fit(&mut self) {}
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Reserve for tinyvec::TinyVec<A> {
+  type Safety = Unsafe;
+  fn reserve(&mut self, additional: usize) {
+    tinyvec::TinyVec::reserve(self, additional)
+  }
+  fn reserve_exact(&mut self, additional: usize) {
+    tinyvec::TinyVec::reserve_exact(self, additional)
+  }
+  fn shrink_to_fit(&mut self) {
+    tinyvec::TinyVec::shrink_to_fit(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn vec() {
+    let mut v = Vec::<i32>::new();
+    <Vec<i32> as Reserve>::reserve_exact(&mut v, 5);
+    assert!(v.capacity() >= 5);
+    <Vec<i32> as Reserve>::shrink_to_fit(&mut v);
+    assert_eq!(v.capacity(), 0);
+  }
+  #[test]
+  fn vec_deque() {
+    let mut v = alloc::collections::VecDeque::<i32>::new();
+    <alloc::collections::VecDeque<i32> as Reserve>::reserve(&mut v, 5);
+    assert!(v.capacity() >= 5);
+  }
+  #[test]
+  fn hash_set() {
+    let mut s = std::collections::HashSet::<i32>::new();
+    <std::collections::HashSet<i32> as Reserve>::reserve_exact(&mut s, 5);
+    assert!(s.capacity() >= 5);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::<i32>::new();
+    <slab::Slab<i32> as Reserve>::reserve(&mut s, 5);
+    assert!(s.capacity() >= 5);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = indexmap::IndexMap::<i32, i32>::new();
+    <indexmap::IndexMap<i32, i32> as Reserve>::reserve_exact(&mut m, 4);
+    assert!(m.capacity() >= 4);
+    let mut s = indexmap::IndexSet::<i32>::new();
+    <indexmap::IndexSet<i32> as Reserve>::reserve(&mut s, 4);
+    assert!(s.capacity() >= 4);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::<i32, i32>::new();
+    <hashbrown::HashMap<i32, i32> as Reserve>::reserve(&mut m, 4);
+    assert!(m.capacity() >= 4);
+    let mut s = hashbrown::HashSet::<i32>::new();
+    <hashbrown::HashSet<i32> as Reserve>::reserve_exact(&mut s, 4);
+    assert!(s.capacity() >= 4);
+  }
+}
@@ -0,0 +1,472 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+use crate::SafetyMarker;
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Provides the ability to remove all values that don't satisfy a predicate from a collection.
+///
+/// The predicate receives the values of sequences and maps and the keys of sets. The predicate is passed as a trait object, so that the trait can be used with dynamically dispatched collections.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::{BTreeSet, HashMap};
+/// use collectivity::Retain;
+///
+/// fn keep_even(col: &mut impl Retain<i32>) {
+///   col.retain(&mut |v| v % 2 == 0);
+/// }
+///
+/// let mut v = vec![1, 2, 3, 4];
+/// keep_even(&mut v);
+/// assert_eq!(v, vec![2, 4]);
+///
+/// let mut m = HashMap::from([("A", 1), ("B", 2)]);
+/// keep_even(&mut m);
+/// assert_eq!(m, HashMap::from([("B", 2)]));
+///
+/// let mut s = BTreeSet::from([1, 2]);
+/// keep_even(&mut s);
+/// assert_eq!(s, BTreeSet::from([2]));
+/// # }
+/// ```
+pub trait Retain<V> {
+  /// Indicates whether the `retain` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Retains only the values for which `f` returns `true`, removing the others.
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool);
+}
+
+impl<V, R: Retain<V>> Retain<V> for &mut R {
+  type Safety = <R as Retain<V>>::Safety;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    <R as Retain<V>>::retain(self, f)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Retain<V> for Vec<V> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    Vec::retain(self, f)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Retain<V> for VecDeque<V> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    VecDeque::retain(self, f)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Retain<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    *self = core::mem::take(self).into_iter().filter(|v| f(v)).collect();
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> Retain<V> for BinaryHeap<V> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    BinaryHeap::retain(self, f)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Retain<V> for BTreeMap<K, V> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    BTreeMap::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> Retain<K> for BTreeSet<K> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&K) -> bool) {
+    BTreeSet::retain(self, f)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Retain<V> for HashMap<K, V, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    HashMap::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> Retain<K> for HashSet<K, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&K) -> bool) {
+    HashSet::retain(self, f)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+use dashmap::{DashMap, DashSet};
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> Retain<V> for DashMap<K, V, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    DashMap::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> Retain<K> for DashSet<K, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&K) -> bool) {
+    DashSet::retain(self, f)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl Retain<SeV> for SeV {
+  type Safety = Safe;
+  /// Filters the items of arrays and the values of objects. Other values are left unchanged.
+  fn retain(&mut self, f: &mut dyn FnMut(&SeV) -> bool) {
+    match self {
+      SeV::Array(a) => a.retain(f),
+      SeV::Object(o) => {
+        *o = core::mem::take(o)
+          .into_iter()
+          .filter(|(_, v)| f(v))
+          .collect()
+      }
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a> Retain<SBV<'a>> for SBV<'a> {
+  type Safety = Safe;
+  /// Filters the items of arrays and the values of objects. Other values are left unchanged.
+  fn retain(&mut self, f: &mut dyn FnMut(&SBV<'a>) -> bool) {
+    match self {
+      SBV::Array(a) => a.retain(f),
+      SBV::Object(o) => o.retain(|_, v| f(v)),
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Retain<SOV> for SOV {
+  type Safety = Safe;
+  /// Filters the items of arrays and the values of objects. Other values are left unchanged.
+  fn retain(&mut self, f: &mut dyn FnMut(&SOV) -> bool) {
+    match self {
+      SOV::Array(a) => a.retain(f),
+      SOV::Object(o) => o.retain(|_, v| f(v)),
+      _ => {}
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Retain<V> for slab::Slab<V> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    slab::Slab::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Retain<A::Item> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&A::Item) -> bool) {
+    smallvec::SmallVec::retain(self, |v| f(v))
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Retain<V> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    IndexMap::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> Retain<K> for IndexSet<K, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&K) -> bool) {
+    IndexSet::retain(self, f)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Retain<V> for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    hashbrown::HashMap::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> Retain<K> for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&K) -> bool) {
+    hashbrown::HashSet::retain(self, f)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Retain<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    arrayvec::ArrayVec::retain(self, |v| f(v))
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Retain<V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    heapless::Vec::retain(self, |v| f(v))
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Retain<V> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  /// Rotates every value through the deque once, pushing back the retained ones
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    for _ in 0..heapless::Deque::len(self) {
+      if let Some(v) = heapless::Deque::pop_front(self) {
+        if f(&v) {
+          let _ = heapless::Deque::push_back(self, v);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> Retain<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  /// Rebuilds the heap from the retained values
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    for v in core::mem::take(self).into_vec() {
+      if f(&v) {
+        let _ = heapless::BinaryHeap::push(self, v);
+      }
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Retain<V> for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&V) -> bool) {
+    heapless::IndexMap::retain(self, |_, v| f(v))
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Retain<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&A::Item) -> bool) {
+    tinyvec::ArrayVec::retain(self, |v| f(v))
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Retain<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn retain(&mut self, f: &mut dyn FnMut(&A::Item) -> bool) {
+    tinyvec::TinyVec::retain(self, |v| f(v))
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = VecDeque::from([1, 2]);
+    <VecDeque<i32> as Retain<i32>>::retain(&mut v, &mut |v| *v > 1);
+    assert_eq!(v, VecDeque::from([2]));
+    let mut l = LinkedList::from([1, 2, 3]);
+    <LinkedList<i32> as Retain<i32>>::retain(&mut l, &mut |v| *v != 2);
+    assert_eq!(l, LinkedList::from([1, 3]));
+    let mut h = BinaryHeap::from([1, 2]);
+    <BinaryHeap<i32> as Retain<i32>>::retain(&mut h, &mut |v| *v < 2);
+    assert_eq!(h.into_vec(), vec![1]);
+    let mut m = BTreeMap::from([(0, 1), (1, 2)]);
+    <BTreeMap<i32, i32> as Retain<i32>>::retain(&mut m, &mut |v| *v > 1);
+    assert_eq!(m, BTreeMap::from([(1, 2)]));
+    let mut s = HashSet::from([0, 1]);
+    <HashSet<i32> as Retain<i32>>::retain(&mut s, &mut |k| *k > 0);
+    assert_eq!(s, HashSet::from([1]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dashmap() {
+    let mut m = DashMap::new();
+    m.insert(0, 1);
+    m.insert(1, 2);
+    <DashMap<i32, i32> as Retain<i32>>::retain(&mut m, &mut |v| *v > 1);
+    assert_eq!(m.len(), 1);
+    assert!(m.contains_key(&1));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    let mut a = serde_json::json!([null, 0]);
+    Retain::retain(&mut a, &mut |v| !v.is_null());
+    assert_eq!(a, serde_json::json!([0]));
+    let mut o = serde_json::json!({ "a": null, "b": 0 });
+    Retain::retain(&mut o, &mut |v| !v.is_null());
+    assert_eq!(o, serde_json::json!({ "b": 0 }));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    s.insert(1);
+    s.insert(2);
+    <slab::Slab<i32> as Retain<i32>>::retain(&mut s, &mut |v| *v > 1);
+    assert_eq!(s.get(0), None);
+    assert_eq!(s.get(1), Some(&2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = smallvec::SmallVec::<[i32; 2]>::from_slice(&[1, 2]);
+    <smallvec::SmallVec<[i32; 2]> as Retain<i32>>::retain(&mut sv, &mut |v| *v > 1);
+    assert_eq!(sv.as_slice(), &[2]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::from([(2, 2), (0, 1), (1, 0)]);
+    <IndexMap<i32, i32> as Retain<i32>>::retain(&mut m, &mut |v| *v != 1);
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&2, &1]);
+    let mut s = IndexSet::from([2, 0, 1]);
+    <IndexSet<i32> as Retain<i32>>::retain(&mut s, &mut |k| *k != 0);
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&2, &1]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(0, 1), (1, 2)]);
+    <hashbrown::HashMap<i32, i32> as Retain<i32>>::retain(&mut m, &mut |v| *v != 1);
+    assert_eq!(m, hashbrown::HashMap::from([(1, 2)]));
+    let mut s = hashbrown::HashSet::from([0, 1]);
+    <hashbrown::HashSet<i32> as Retain<i32>>::retain(&mut s, &mut |k| *k != 0);
+    assert_eq!(s, hashbrown::HashSet::from([1]));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 2>::from([1, 2]);
+    <arrayvec::ArrayVec<i32, 2> as Retain<i32>>::retain(&mut v, &mut |v| *v > 1);
+    assert_eq!(v.as_slice(), &[2]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut d = heapless::Deque::<i32, 4>::new();
+    d.push_back(1).unwrap();
+    d.push_front(2).unwrap();
+    d.push_back(3).unwrap();
+    <heapless::Deque<i32, 4> as Retain<i32>>::retain(&mut d, &mut |v| *v > 1);
+    assert_eq!(d.iter().collect::<Vec<_>>(), [&2, &3]);
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Max, 4>::new();
+    h.push(1).unwrap();
+    h.push(3).unwrap();
+    h.push(2).unwrap();
+    <heapless::BinaryHeap<i32, heapless::binary_heap::Max, 4> as Retain<i32>>::retain(
+      &mut h,
+      &mut |v| *v < 3,
+    );
+    assert_eq!(h.peek(), Some(&2));
+    assert_eq!(h.len(), 2);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 2]>::from([1, 2]);
+    <tinyvec::ArrayVec<[i32; 2]> as Retain<i32>>::retain(&mut v, &mut |v| *v > 1);
+    assert_eq!(v.as_slice(), &[2]);
+  }
+}
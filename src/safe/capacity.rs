@@ -0,0 +1,6 @@
+use crate::{Capacity, Safe};
+
+/// `Capacity` restricted to implementations that may not panic
+pub trait SafeCapacity: Capacity<Safety = Safe> {}
+
+impl<C: ?Sized + Capacity<Safety = Safe>> SafeCapacity for C {}
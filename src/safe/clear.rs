@@ -0,0 +1,6 @@
+use crate::{Clear, Safe};
+
+/// `Clear` restricted to implementations that may not panic
+pub trait SafeClear: Clear<Safety = Safe> {}
+
+impl<C: ?Sized + Clear<Safety = Safe>> SafeClear for C {}
@@ -0,0 +1,6 @@
+use crate::{CollectFrom, Safe};
+
+/// `CollectFrom` restricted to implementations that may not panic
+pub trait SafeCollectFrom<V>: CollectFrom<V, Safety = Safe> {}
+
+impl<V, C: CollectFrom<V, Safety = Safe>> SafeCollectFrom<V> for C {}
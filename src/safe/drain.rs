@@ -0,0 +1,6 @@
+use crate::{Drain, Safe};
+
+/// `Drain` restricted to implementations that may not panic
+pub trait SafeDrain: Drain<Safety = Safe> {}
+
+impl<C: ?Sized + Drain<Safety = Safe>> SafeDrain for C {}
@@ -0,0 +1,6 @@
+use crate::{DrainRange, Safe};
+
+/// `DrainRange` restricted to implementations that may not panic
+pub trait SafeDrainRange: DrainRange<Safety = Safe> {}
+
+impl<C: ?Sized + DrainRange<Safety = Safe>> SafeDrainRange for C {}
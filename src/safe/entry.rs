@@ -0,0 +1,6 @@
+use crate::{Entry, Safe};
+
+/// `Entry` restricted to implementations that may not panic
+pub trait SafeEntry<K, V>: Entry<K, V, Safety = Safe> {}
+
+impl<K, V, C: ?Sized + Entry<K, V, Safety = Safe>> SafeEntry<K, V> for C {}
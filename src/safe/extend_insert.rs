@@ -0,0 +1,6 @@
+use crate::{ExtendInsert, Safe};
+
+/// `ExtendInsert` restricted to implementations that may not panic
+pub trait SafeExtendInsert<K, V>: ExtendInsert<K, V, Safety = Safe> {}
+
+impl<K, V, C: ?Sized + ExtendInsert<K, V, Safety = Safe>> SafeExtendInsert<K, V> for C {}
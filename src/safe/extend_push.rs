@@ -0,0 +1,6 @@
+use crate::{ExtendPush, Safe};
+
+/// `ExtendPush` restricted to implementations that may not panic
+pub trait SafeExtendPush<V>: ExtendPush<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + ExtendPush<V, Safety = Safe>> SafeExtendPush<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{First, Safe};
+
+/// `First` restricted to implementations that may not panic
+pub trait SafeFirst<V>: First<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + First<V, Safety = Safe>> SafeFirst<V> for C {}
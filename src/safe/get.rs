@@ -0,0 +1,6 @@
+use crate::{Get, Safe};
+
+/// `Get` restricted to implementations that may not panic
+pub trait SafeGet<K>: Get<K, Safety = Safe> {}
+
+impl<K, C: ?Sized + Get<K, Safety = Safe>> SafeGet<K> for C {}
@@ -0,0 +1,6 @@
+use crate::{GetMut, Safe};
+
+/// `GetMut` restricted to implementations that may not panic
+pub trait SafeGetMut<K>: GetMut<K, Safety = Safe> {}
+
+impl<K, C: ?Sized + GetMut<K, Safety = Safe>> SafeGetMut<K> for C {}
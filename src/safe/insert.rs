@@ -0,0 +1,31 @@
+use crate::{Insert, Safe};
+
+/// `Insert` restricted to implementations that may not panic
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::SafeInsert;
+///
+/// fn store(col: &mut impl SafeInsert<usize, i32>) {
+///   col.insert(5, 1);
+/// }
+///
+/// store(&mut HashMap::new());
+/// # }
+/// ```
+///
+/// Index-based inserts may panic, so `Vec` is rejected at compile time:
+/// ```compile_fail
+/// use collectivity::SafeInsert;
+///
+/// fn store(col: &mut impl SafeInsert<usize, i32>) {
+///   col.insert(5, 1);
+/// }
+///
+/// store(&mut Vec::new());
+/// ```
+pub trait SafeInsert<K, V>: Insert<K, V, Safety = Safe> {}
+
+impl<K, V, C: ?Sized + Insert<K, V, Safety = Safe>> SafeInsert<K, V> for C {}
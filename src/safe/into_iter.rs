@@ -0,0 +1,6 @@
+use crate::{IntoIter, Safe};
+
+/// `IntoIter` restricted to implementations that may not panic
+pub trait SafeIntoIter: IntoIter<Safety = Safe> {}
+
+impl<C: ?Sized + IntoIter<Safety = Safe>> SafeIntoIter for C {}
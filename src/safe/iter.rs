@@ -0,0 +1,6 @@
+use crate::{Iter, Safe};
+
+/// `Iter` restricted to implementations that may not panic
+pub trait SafeIter: Iter<Safety = Safe> {}
+
+impl<C: ?Sized + Iter<Safety = Safe>> SafeIter for C {}
@@ -0,0 +1,6 @@
+use crate::{IterMut, Safe};
+
+/// `IterMut` restricted to implementations that may not panic
+pub trait SafeIterMut: IterMut<Safety = Safe> {}
+
+impl<C: ?Sized + IterMut<Safety = Safe>> SafeIterMut for C {}
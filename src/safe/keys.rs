@@ -0,0 +1,6 @@
+use crate::{Keys, Safe};
+
+/// `Keys` restricted to implementations that may not panic
+pub trait SafeKeys: Keys<Safety = Safe> {}
+
+impl<C: ?Sized + Keys<Safety = Safe>> SafeKeys for C {}
@@ -0,0 +1,6 @@
+use crate::{Last, Safe};
+
+/// `Last` restricted to implementations that may not panic
+pub trait SafeLast<V>: Last<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + Last<V, Safety = Safe>> SafeLast<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{Len, Safe};
+
+/// `Len` restricted to implementations that may not panic
+pub trait SafeLen: Len<Safety = Safe> {}
+
+impl<C: ?Sized + Len<Safety = Safe>> SafeLen for C {}
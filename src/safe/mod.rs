@@ -0,0 +1,73 @@
+mod capacity;
+mod clear;
+mod collect_from;
+mod drain;
+mod drain_range;
+mod entry;
+mod extend_insert;
+mod extend_push;
+mod first;
+mod get;
+mod get_mut;
+mod insert;
+mod into_iter;
+mod iter;
+mod iter_mut;
+mod keys;
+mod last;
+mod len;
+mod peek;
+mod pop;
+mod pop_back;
+mod pop_first;
+mod pop_front;
+mod pop_last;
+mod push;
+mod push_front;
+mod range;
+mod remove;
+mod reserve;
+mod retain;
+mod try_get;
+mod try_insert;
+mod try_push;
+mod try_remove;
+mod try_reserve;
+mod values;
+
+pub use capacity::*;
+pub use clear::*;
+pub use collect_from::*;
+pub use drain::*;
+pub use drain_range::*;
+pub use entry::*;
+pub use extend_insert::*;
+pub use extend_push::*;
+pub use first::*;
+pub use get::*;
+pub use get_mut::*;
+pub use insert::*;
+pub use into_iter::*;
+pub use iter::*;
+pub use iter_mut::*;
+pub use keys::*;
+pub use last::*;
+pub use len::*;
+pub use peek::*;
+pub use pop::*;
+pub use pop_back::*;
+pub use pop_first::*;
+pub use pop_front::*;
+pub use pop_last::*;
+pub use push::*;
+pub use push_front::*;
+pub use range::*;
+pub use remove::*;
+pub use reserve::*;
+pub use retain::*;
+pub use try_get::*;
+pub use try_insert::*;
+pub use try_push::*;
+pub use try_remove::*;
+pub use try_reserve::*;
+pub use values::*;
@@ -0,0 +1,6 @@
+use crate::{Peek, Safe};
+
+/// `Peek` restricted to implementations that may not panic
+pub trait SafePeek<V>: Peek<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + Peek<V, Safety = Safe>> SafePeek<V> for C {}
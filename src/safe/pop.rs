@@ -0,0 +1,6 @@
+use crate::{Pop, Safe};
+
+/// `Pop` restricted to implementations that may not panic
+pub trait SafePop<V>: Pop<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + Pop<V, Safety = Safe>> SafePop<V> for C {}
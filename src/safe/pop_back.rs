@@ -0,0 +1,6 @@
+use crate::{PopBack, Safe};
+
+/// `PopBack` restricted to implementations that may not panic
+pub trait SafePopBack<V>: PopBack<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + PopBack<V, Safety = Safe>> SafePopBack<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{PopFirst, Safe};
+
+/// `PopFirst` restricted to implementations that may not panic
+pub trait SafePopFirst<V>: PopFirst<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + PopFirst<V, Safety = Safe>> SafePopFirst<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{PopFront, Safe};
+
+/// `PopFront` restricted to implementations that may not panic
+pub trait SafePopFront<V>: PopFront<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + PopFront<V, Safety = Safe>> SafePopFront<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{PopLast, Safe};
+
+/// `PopLast` restricted to implementations that may not panic
+pub trait SafePopLast<V>: PopLast<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + PopLast<V, Safety = Safe>> SafePopLast<V> for C {}
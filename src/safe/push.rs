@@ -0,0 +1,6 @@
+use crate::{Push, Safe};
+
+/// `Push` restricted to implementations that may not panic
+pub trait SafePush<V>: Push<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + Push<V, Safety = Safe>> SafePush<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{PushFront, Safe};
+
+/// `PushFront` restricted to implementations that may not panic
+pub trait SafePushFront<V>: PushFront<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + PushFront<V, Safety = Safe>> SafePushFront<V> for C {}
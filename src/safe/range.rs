@@ -0,0 +1,6 @@
+use crate::{Range, Safe};
+
+/// `Range` restricted to implementations that may not panic
+pub trait SafeRange<K>: Range<K, Safety = Safe> {}
+
+impl<K, C: ?Sized + Range<K, Safety = Safe>> SafeRange<K> for C {}
@@ -0,0 +1,6 @@
+use crate::{Remove, Safe};
+
+/// `Remove` restricted to implementations that may not panic
+pub trait SafeRemove<K, V>: Remove<K, V, Safety = Safe> {}
+
+impl<K, V, C: ?Sized + Remove<K, V, Safety = Safe>> SafeRemove<K, V> for C {}
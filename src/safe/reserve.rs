@@ -0,0 +1,6 @@
+use crate::{Reserve, Safe};
+
+/// `Reserve` restricted to implementations that may not panic
+pub trait SafeReserve: Reserve<Safety = Safe> {}
+
+impl<C: ?Sized + Reserve<Safety = Safe>> SafeReserve for C {}
@@ -0,0 +1,6 @@
+use crate::{Retain, Safe};
+
+/// `Retain` restricted to implementations that may not panic
+pub trait SafeRetain<V>: Retain<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + Retain<V, Safety = Safe>> SafeRetain<V> for C {}
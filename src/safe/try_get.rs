@@ -0,0 +1,6 @@
+use crate::{Safe, TryGet};
+
+/// `TryGet` restricted to implementations that may not panic
+pub trait SafeTryGet<K>: TryGet<K, Safety = Safe> {}
+
+impl<K, C: ?Sized + TryGet<K, Safety = Safe>> SafeTryGet<K> for C {}
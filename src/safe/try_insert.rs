@@ -0,0 +1,6 @@
+use crate::{Safe, TryInsert};
+
+/// `TryInsert` restricted to implementations that may not panic
+pub trait SafeTryInsert<K, V>: TryInsert<K, V, Safety = Safe> {}
+
+impl<K, V, C: ?Sized + TryInsert<K, V, Safety = Safe>> SafeTryInsert<K, V> for C {}
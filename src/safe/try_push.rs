@@ -0,0 +1,6 @@
+use crate::{Safe, TryPush};
+
+/// `TryPush` restricted to implementations that may not panic
+pub trait SafeTryPush<V>: TryPush<V, Safety = Safe> {}
+
+impl<V, C: ?Sized + TryPush<V, Safety = Safe>> SafeTryPush<V> for C {}
@@ -0,0 +1,6 @@
+use crate::{Safe, TryRemove};
+
+/// `TryRemove` restricted to implementations that may not panic
+pub trait SafeTryRemove<K, V>: TryRemove<K, V, Safety = Safe> {}
+
+impl<K, V, C: ?Sized + TryRemove<K, V, Safety = Safe>> SafeTryRemove<K, V> for C {}
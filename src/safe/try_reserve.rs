@@ -0,0 +1,6 @@
+use crate::{Safe, TryReserve};
+
+/// `TryReserve` restricted to implementations that may not panic
+pub trait SafeTryReserve: TryReserve<Safety = Safe> {}
+
+impl<C: ?Sized + TryReserve<Safety = Safe>> SafeTryReserve for C {}
@@ -0,0 +1,6 @@
+use crate::{Safe, Values};
+
+/// `Values` restricted to implementations that may not panic
+pub trait SafeValues: Values<Safety = Safe> {}
+
+impl<C: ?Sized + Values<Safety = Safe>> SafeValues for C {}
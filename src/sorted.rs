@@ -0,0 +1,50 @@
+use crate::Ordered;
+use core::ops::Deref;
+
+/// Wrapper around an array or `Vec` that keeps its values sorted, so that the sequence can be used wherever an ordered collection is expected.
+///
+/// The values can be read through `Deref` and the forwarded `Iter`, `IntoIter`, `Len` and `Get` implementations, but only removed through `PopFirst` and `PopLast`, which preserve the order.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use collectivity::{First, Iter, Last, Len, Sorted};
+///
+/// let s = Sorted::new(vec![3, 1, 2]);
+/// assert_eq!(*s, vec![1, 2, 3]);
+/// assert_eq!(s.first(), Some(&1));
+/// assert_eq!(s.last(), Some(&3));
+/// assert_eq!(Iter::iter(&s).collect::<Vec<_>>(), [&1, &2, &3]);
+/// assert_eq!(Len::len(&s), 3);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Sorted<C>(C);
+
+impl<C> Sorted<C> {
+  /// Sorts the values of `c` and wraps it.
+  pub fn new<V: Ord>(mut c: C) -> Self
+  where
+    C: AsMut<[V]>,
+  {
+    c.as_mut().sort_unstable();
+    Sorted(c)
+  }
+  /// Unwraps the underlying collection.
+  pub fn into_inner(self) -> C {
+    self.0
+  }
+  #[cfg(feature = "alloc")]
+  pub(crate) fn inner_mut(&mut self) -> &mut C {
+    &mut self.0
+  }
+}
+
+impl<C> Deref for Sorted<C> {
+  type Target = C;
+  fn deref(&self) -> &C {
+    &self.0
+  }
+}
+
+impl<C> Ordered for Sorted<C> {}
@@ -0,0 +1,49 @@
+use crate::{Remove, Stable};
+
+/// `Remove` restricted to implementations that leave the keys of other entries unchanged, so that keys held elsewhere stay valid.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::HashMap;
+/// use collectivity::StableKeys;
+///
+/// fn take<'a>(col: &mut impl StableKeys<&'a usize, char>, handle: &'a usize) -> Option<char> {
+///   col.remove(handle)
+/// }
+///
+/// let mut m = HashMap::from([(0, 'a'), (1, 'b')]);
+/// assert_eq!(take(&mut m, &0), Some('a'));
+/// assert_eq!(m[&1], 'b');
+/// # }
+/// ```
+///
+/// Removing from a `Vec` shifts the following indexes, so it's rejected at compile time:
+/// ```compile_fail
+/// use collectivity::StableKeys;
+///
+/// fn take(col: &mut impl StableKeys<usize, char>, handle: usize) -> Option<char> {
+///   col.remove(handle)
+/// }
+///
+/// take(&mut vec!['a', 'b'], 0);
+/// ```
+pub trait StableKeys<K, V>: Remove<K, V, KeyStability = Stable> {}
+
+impl<K, V, C: ?Sized + Remove<K, V, KeyStability = Stable>> StableKeys<K, V> for C {}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  fn take(col: &mut impl StableKeys<usize, i32>, k: usize) -> Option<i32> {
+    col.remove(k)
+  }
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    let handles = [s.insert(1), s.insert(2)];
+    assert_eq!(take(&mut s, handles[0]), Some(1));
+    assert_eq!(s[handles[1]], 2);
+  }
+}
@@ -0,0 +1,53 @@
+use crate::{Get, Insert, Len, Push, Remove, SubLinear};
+
+/// `Get` restricted to implementations with sub-linear time complexity
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use std::collections::{BTreeMap, HashMap};
+/// use collectivity::SubLinearGet;
+///
+/// fn lookup<'a>(col: &'a impl SubLinearGet<&'a str>, k: &'a str) -> bool {
+///   col.contains(k)
+/// }
+///
+/// assert!(lookup(&HashMap::from([("A".to_string(), 1)]), "A"));
+/// assert!(!lookup(&BTreeMap::<String, i32>::new(), "A"));
+/// # }
+/// ```
+///
+/// `LinkedList` lookups are linear, so the list is rejected at compile time:
+/// ```compile_fail
+/// use std::collections::LinkedList;
+/// use collectivity::SubLinearGet;
+///
+/// fn lookup(col: &impl SubLinearGet<usize>, k: usize) -> bool {
+///   col.contains(k)
+/// }
+///
+/// lookup(&LinkedList::from([1]), 0);
+/// ```
+pub trait SubLinearGet<K>: Get<K> {}
+
+impl<K, C: ?Sized + Get<K>> SubLinearGet<K> for C where C::Complexity: SubLinear {}
+
+/// `Insert` restricted to implementations with sub-linear time complexity
+pub trait SubLinearInsert<K, V>: Insert<K, V> {}
+
+impl<K, V, C: ?Sized + Insert<K, V>> SubLinearInsert<K, V> for C where C::Complexity: SubLinear {}
+
+/// `Remove` restricted to implementations with sub-linear time complexity
+pub trait SubLinearRemove<K, V>: Remove<K, V> {}
+
+impl<K, V, C: ?Sized + Remove<K, V>> SubLinearRemove<K, V> for C where C::Complexity: SubLinear {}
+
+/// `Push` restricted to implementations with sub-linear time complexity
+pub trait SubLinearPush<V>: Push<V> {}
+
+impl<V, C: ?Sized + Push<V>> SubLinearPush<V> for C where C::Complexity: SubLinear {}
+
+/// `Len` restricted to implementations with sub-linear time complexity
+pub trait SubLinearLen: Len {}
+
+impl<C: ?Sized + Len> SubLinearLen for C where C::Complexity: SubLinear {}
@@ -0,0 +1,20 @@
+/// Selects swap removal for a key, moving the last entry into the vacated position instead of shifting all following entries.
+///
+/// Swap removal runs in constant time but doesn't preserve the order of the remaining entries.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "indexmap")]
+/// # {
+/// use collectivity::{Remove, Swap};
+/// use indexmap::IndexMap;
+///
+/// let mut m = IndexMap::from([("A", 1), ("B", 2), ("C", 3)]);
+/// assert_eq!(Remove::remove(&mut m, Swap(&"A")), Some(1));
+/// assert_eq!(m.keys().collect::<Vec<_>>(), [&"C", &"B"]);
+/// assert_eq!(Remove::remove(&mut m, &"C"), Some(3));
+/// assert_eq!(m.keys().collect::<Vec<_>>(), [&"B"]);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Swap<K>(pub K);
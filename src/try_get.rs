@@ -0,0 +1,648 @@
+#[cfg(feature = "heapless")]
+use crate::Get;
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+use crate::ValueKind;
+use crate::{CollectionError, Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use core::borrow::Borrow;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Provides access to a value at a specified key, reporting why the value is unavailable.
+///
+/// Indexed collections report `OutOfBounds`, keyed collections report `KeyNotFound`, and JSON values of the wrong kind report `WrongValueKind`.
+///
+/// ## Examples
+/// ```
+/// use collectivity::{CollectionError, TryGet};
+///
+/// let v = vec![1, 2];
+/// assert_eq!(v.try_get(1), Ok(&2));
+/// assert_eq!(
+///   v.try_get(2),
+///   Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+/// );
+/// ```
+pub trait TryGet<K> {
+  /// Indicates whether the `try_get` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the value returned by `try_get`.
+  type Value<'a>
+  where
+    Self: 'a;
+  /// Returns the value at `k`, or the reason it can't be accessed.
+  fn try_get<'a>(&'a self, k: K) -> Result<Self::Value<'a>, CollectionError>;
+}
+
+impl<V> TryGet<usize> for [V] {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    <[V]>::get(self, k).ok_or(CollectionError::OutOfBounds {
+      index: k,
+      len: self.len(),
+    })
+  }
+}
+
+impl<V, const N: usize> TryGet<usize> for [V; N] {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    <[V]>::try_get(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryGet<usize> for Vec<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    <[V]>::try_get(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryGet<usize> for VecDeque<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    self.get(k).ok_or(CollectionError::OutOfBounds {
+      index: k,
+      len: self.len(),
+    })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryGet<usize> for LinkedList<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    self.iter().nth(k).ok_or(CollectionError::OutOfBounds {
+      index: k,
+      len: self.len(),
+    })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord, V> TryGet<&'k Q> for BTreeMap<K, V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a V, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord> TryGet<&'k Q> for BTreeSet<K> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a K, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> TryGet<&'k Q>
+  for HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a V, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryGet<&'k Q>
+  for HashSet<K, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a K, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher + Clone> TryGet<&'k Q>
+  for dashmap::DashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = dashmap::mapref::one::Ref<'a, K, V, S>
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<Self::Value<'a>, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher + Clone> TryGet<&'k Q>
+  for dashmap::DashSet<K, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = dashmap::setref::one::Ref<'a, K, S>
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<Self::Value<'a>, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl<'k> TryGet<&'k str> for SeV {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SeV
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k str) -> Result<&'a SeV, CollectionError> {
+    match self {
+      SeV::Object(o) => o.get(k).ok_or(CollectionError::KeyNotFound),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryGet<usize> for SeV {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SeV
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&SeV, CollectionError> {
+    match self {
+      SeV::Array(a) => a.try_get(k),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'k, 'v> TryGet<&'k str> for SBV<'v> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SBV<'v>
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k str) -> Result<&'a SBV<'v>, CollectionError> {
+    match self {
+      SBV::Object(o) => o.get(k).ok_or(CollectionError::KeyNotFound),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> TryGet<usize> for SBV<'v> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SBV<'v>
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&SBV<'v>, CollectionError> {
+    match self {
+      SBV::Array(a) => a.try_get(k),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'k> TryGet<&'k str> for SOV {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SOV
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k str) -> Result<&'a SOV, CollectionError> {
+    match self {
+      SOV::Object(o) => o.get(k).ok_or(CollectionError::KeyNotFound),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl TryGet<usize> for SOV {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a SOV
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&SOV, CollectionError> {
+    match self {
+      SOV::Array(a) => a.try_get(k),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> TryGet<usize> for slab::Slab<V> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> TryGet<usize> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&A::Item, CollectionError> {
+    <[A::Item]>::try_get(self, k)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use crate::Position;
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> TryGet<&'k Q>
+  for indexmap::IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a V, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryGet<Position> for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Value<'a>
+    = (&'a K, &'a V)
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: Position) -> Result<Self::Value<'a>, CollectionError> {
+    self.get_index(k.0).ok_or(CollectionError::OutOfBounds {
+      index: k.0,
+      len: self.len(),
+    })
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryGet<&'k Q>
+  for indexmap::IndexSet<K, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a K, CollectionError> {
+    self.get(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> TryGet<Position> for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: Position) -> Result<&K, CollectionError> {
+    self.get_index(k.0).ok_or(CollectionError::OutOfBounds {
+      index: k.0,
+      len: self.len(),
+    })
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> TryGet<&'k Q>
+  for hashbrown::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a V, CollectionError> {
+    hashbrown::HashMap::get(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryGet<&'k Q>
+  for hashbrown::HashSet<K, S>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a K
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a K, CollectionError> {
+    hashbrown::HashSet::get(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> TryGet<usize> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    <[V]>::try_get(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryGet<usize> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    <[V]>::try_get(self, k)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryGet<usize> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&V, CollectionError> {
+    <Self as Get<usize>>::get(self, k).ok_or(CollectionError::OutOfBounds {
+      index: k,
+      len: heapless::Deque::len(self),
+    })
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher, const N: usize>
+  TryGet<&'k Q> for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  type Value<'a>
+    = &'a V
+  where
+    Self: 'a;
+
+  fn try_get<'a>(&'a self, k: &'k Q) -> Result<&'a V, CollectionError> {
+    heapless::IndexMap::get(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TryGet<usize> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&A::Item, CollectionError> {
+    <[A::Item]>::try_get(self, k)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> TryGet<usize> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Value<'a>
+    = &'a A::Item
+  where
+    Self: 'a;
+
+  fn try_get(&self, k: usize) -> Result<&A::Item, CollectionError> {
+    <[A::Item]>::try_get(self, k)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!([1, 2].try_get(0), Ok(&1));
+    assert_eq!(
+      <[i32]>::try_get(&[1, 2][..], 2),
+      Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+      <VecDeque<i32> as TryGet<usize>>::try_get(&VecDeque::from([1]), 3),
+      Err(CollectionError::OutOfBounds { index: 3, len: 1 })
+    );
+    assert_eq!(
+      <LinkedList<i32> as TryGet<usize>>::try_get(&LinkedList::from([1, 2]), 1),
+      Ok(&2)
+    );
+    let m = BTreeMap::from([(String::from("A"), 1)]);
+    assert_eq!(m.try_get("A"), Ok(&1));
+    assert_eq!(m.try_get("B"), Err(CollectionError::KeyNotFound));
+    let s = HashSet::from([1]);
+    assert_eq!(s.try_get(&1), Ok(&1));
+    assert_eq!(s.try_get(&2), Err(CollectionError::KeyNotFound));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  use serde_json::json;
+  #[test]
+  fn serde_json() {
+    let v = json!({"A": [1]});
+    assert_eq!(v.try_get("A").and_then(|a| a.try_get(0)), Ok(&json!(1)));
+    assert_eq!(v.try_get("B"), Err(CollectionError::KeyNotFound));
+    assert_eq!(
+      v.try_get(0),
+      Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: "object"
+      })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  #[test]
+  fn simd_json() {
+    let v = SOV::Array(vec![SOV::from(1)]);
+    assert_eq!(v.try_get(0), Ok(&SOV::from(1)));
+    assert_eq!(
+      v.try_get("A"),
+      Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: "array"
+      })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    let k = s.insert(1);
+    s.insert(2);
+    s.remove(k);
+    assert_eq!(s.try_get(k), Err(CollectionError::KeyNotFound));
+    assert_eq!(s.try_get(1), Ok(&2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let m = indexmap::IndexMap::from([("B", 2), ("A", 1)]);
+    assert_eq!(TryGet::try_get(&m, &"A"), Ok(&1));
+    assert_eq!(TryGet::try_get(&m, &"C"), Err(CollectionError::KeyNotFound));
+    assert_eq!(TryGet::try_get(&m, Position(0)), Ok((&"B", &2)));
+    assert_eq!(
+      TryGet::try_get(&m, Position(2)),
+      Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+    );
+    let s = indexmap::IndexSet::from([2, 1]);
+    assert_eq!(TryGet::try_get(&s, Position(1)), Ok(&1));
+    assert_eq!(TryGet::try_get(&s, &3), Err(CollectionError::KeyNotFound));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let m = hashbrown::HashMap::from([(0, 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as TryGet<&i32>>::try_get(&m, &0),
+      Ok(&1)
+    );
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as TryGet<&i32>>::try_get(&m, &1),
+      Err(CollectionError::KeyNotFound)
+    );
+    let s = hashbrown::HashSet::from([0]);
+    assert_eq!(
+      <hashbrown::HashSet<i32> as TryGet<&i32>>::try_get(&s, &0),
+      Ok(&0)
+    );
+  }
+}
@@ -1,22 +1,32 @@
-use crate::Insert;
-#[cfg(feature = "std")]
-use std::{
-  collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
-  hash::Hash,
+#[cfg(feature = "alloc")]
+use crate::TryReserve;
+use crate::{CollectionError, Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  vec::Vec,
 };
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
 
 /// Provides the ability to safely insert a provided value at a specified index, possibly overwriting the previous value.
 ///
+/// Implementations never panic, except for `DashMap`, `DashSet` and the object impls of JSON values, which cannot grow their storage fallibly and are marked `Unsafe`.
+/// Fixed-length collections overwrite the value at an existing index, growable ones accept any index up to their length, and anything else is reported as a `CollectionError` with the collection left unchanged.
+///
 /// ## Examples
 /// ```
+/// # #[cfg(feature = "alloc")] {
 /// use std::borrow::Cow;
-/// use collectivity::{InsertError, TryInsert};
+/// use collectivity::{CollectionError, TryInsert};
 ///
 /// fn try_insert<'a, V>(
 ///   col: &mut impl TryInsert<usize, V>,
 ///   pos: usize,
 ///   val: V
-/// ) -> Result<(), InsertError> {
+/// ) -> Result<(), CollectionError> {
 ///   col.try_insert(pos, val)
 /// }
 ///
@@ -28,172 +38,195 @@ use std::{
 /// let mut v = [0, 1];
 /// try_insert(&mut v, 0, 1);
 /// assert_eq!(v[0], 1);
+/// assert_eq!(
+///   try_insert(&mut v, 2, 1),
+///   Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+/// );
+/// # }
 /// ```
 pub trait TryInsert<K, V> {
+  /// Indicates whether the `try_insert` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
   /// Tries to insert value `v` at key `k`.
-  fn try_insert(&mut self, k: K, v: V) -> Result<(), InsertError>;
-}
-
-#[derive(Debug)]
-/// `Insert` error
-pub enum InsertError {
-  /// Indicates the inserted key is out of bounds.
-  OutOfBounds,
-  /// Indicated the container type doesn't support the attempted insert operation
-  UnsupportedContainerType,
-}
-
-#[cfg(feature = "std")]
-impl std::fmt::Display for InsertError {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "The inserted key is out of bounds")
-  }
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError>;
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for InsertError {}
-
-impl<'i, K, V, T: TryInsert<K, V>> TryInsert<K, V> for &'i mut T {
-  fn try_insert<'a>(&'a mut self, k: K, v: V) -> Result<(), InsertError> {
+impl<K, V, T: TryInsert<K, V>> TryInsert<K, V> for &mut T {
+  type Safety = <T as TryInsert<K, V>>::Safety;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
     <T as TryInsert<K, V>>::try_insert(self, k, v)
   }
 }
 
 impl<V, const N: usize> TryInsert<usize, V> for [V; N] {
-  fn try_insert(&mut self, k: usize, v: V) -> Result<(), InsertError> {
-    if self.len() >= k {
-      Ok(self[k] = v)
-    } else {
-      Err(InsertError::OutOfBounds)
-    }
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    <[V] as TryInsert<usize, V>>::try_insert(self, k, v)
   }
 }
 
 impl<V> TryInsert<usize, V> for [V] {
-  fn try_insert(&mut self, k: usize, v: V) -> Result<(), InsertError> {
-    if self.len() >= k {
-      Ok(self.insert(k, v))
-    } else {
-      Err(InsertError::OutOfBounds)
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    let len = self.len();
+    match <[V]>::get_mut(self, k) {
+      Some(e) => {
+        *e = v;
+        Ok(())
+      }
+      None => Err(CollectionError::OutOfBounds { index: k, len }),
     }
   }
 }
 
+#[cfg(feature = "alloc")]
 impl<V> TryInsert<usize, V> for Vec<V> {
-  fn try_insert(&mut self, k: usize, v: V) -> Result<(), InsertError> {
-    if self.len() >= k {
-      Ok(self.insert(k, v))
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      <Self as TryReserve>::try_reserve(self, 1)?;
+      self.insert(k, v);
+      Ok(())
     } else {
-      Err(InsertError::OutOfBounds)
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
     }
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> TryInsert<usize, V> for VecDeque<V> {
-  fn try_insert(&mut self, k: usize, v: V) -> Result<(), InsertError> {
-    if self.len() >= k {
-      Ok(self.insert(k, v))
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      <Self as TryReserve>::try_reserve(self, 1)?;
+      self.insert(k, v);
+      Ok(())
     } else {
-      Err(InsertError::OutOfBounds)
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
     }
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<V> TryInsert<usize, V> for LinkedList<V> {
-  fn try_insert(&mut self, k: usize, v: V) -> Result<(), InsertError> {
-    if self.len() >= k {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    if k <= self.len() {
       let mut rest = self.split_off(k);
       self.push_back(v);
       self.append(&mut rest);
       Ok(())
     } else {
-      Err(InsertError::OutOfBounds)
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
     }
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<K: Ord, V> TryInsert<K, V> for BTreeMap<K, V> {
-  fn try_insert(&mut self, k: K, v: V) -> Result<(), InsertError> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
     self.insert(k, v);
     Ok(())
   }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<K: Ord> TryInsert<K, ()> for BTreeSet<K> {
-  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), InsertError> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), CollectionError> {
     self.insert(k);
     Ok(())
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash, V> TryInsert<K, V> for HashMap<K, V> {
-  fn try_insert(&mut self, k: K, v: V) -> Result<(), InsertError> {
+impl<K: Eq + Hash, V, S: BuildHasher> TryInsert<K, V> for HashMap<K, V, S> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
     self.insert(k, v);
     Ok(())
   }
 }
 
 #[cfg(feature = "std")]
-impl<K: Eq + Hash> TryInsert<K, ()> for HashSet<K> {
-  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), InsertError> {
+impl<K: Eq + Hash, S: BuildHasher> TryInsert<K, ()> for HashSet<K, S> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
     self.insert(k);
     Ok(())
   }
 }
 
+#[cfg(any(feature = "dashmap", feature = "serde_json", feature = "simd-json"))]
+use crate::Unsafe;
 #[cfg(feature = "dashmap")]
 use dashmap::{DashMap, DashSet};
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash, V> TryInsert<K, V> for DashMap<K, V> {
-  fn try_insert(&mut self, k: K, v: V) -> Result<(), InsertError> {
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> TryInsert<K, V> for DashMap<K, V, S> {
+  /// `DashMap` provides no fallible way of growing its storage
+  type Safety = Unsafe;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
     DashMap::insert(self, k, v);
     Ok(())
   }
 }
 
 #[cfg(feature = "dashmap")]
-impl<K: Eq + Hash> TryInsert<K, ()> for DashSet<K> {
-  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), InsertError> {
+impl<K: Eq + Hash, S: BuildHasher + Clone> TryInsert<K, ()> for DashSet<K, S> {
+  /// `DashSet` provides no fallible way of growing its storage
+  type Safety = Unsafe;
+  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), CollectionError> {
     DashSet::insert(self, k);
     Ok(())
   }
 }
 
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+use crate::ValueKind;
 #[cfg(feature = "serde_json")]
 use serde_json::Value as SeV;
 
 #[cfg(feature = "serde_json")]
 impl TryInsert<usize, SeV> for SeV {
-  fn try_insert(&mut self, k: usize, v: SeV) -> Result<(), InsertError> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: SeV) -> Result<(), CollectionError> {
     match self {
-      SeV::Array(a) => {
-        if a.len() >= k {
-          a.insert(k, v);
-          Ok(())
-        } else {
-          Err(InsertError::OutOfBounds)
-        }
-      }
-      _ => Err(InsertError::UnsupportedContainerType),
+      SeV::Array(a) => <Vec<SeV> as TryInsert<usize, SeV>>::try_insert(a, k, v),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
     }
   }
 }
 
 #[cfg(feature = "serde_json")]
 impl TryInsert<String, SeV> for SeV {
-  fn try_insert(&mut self, k: String, v: SeV) -> Result<(), InsertError> {
+  /// `serde_json::Map` provides no fallible way of growing its storage
+  type Safety = Unsafe;
+  fn try_insert(&mut self, k: String, v: SeV) -> Result<(), CollectionError> {
     match self {
       SeV::Object(o) => {
         o.insert(k, v);
         Ok(())
       }
-      _ => Err(InsertError::UnsupportedContainerType),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
     }
   }
 }
@@ -203,188 +236,664 @@ use simd_json::{cow::Cow, BorrowedValue as SBV, OwnedValue as SOV};
 
 #[cfg(feature = "simd-json")]
 impl<'a> TryInsert<usize, SBV<'a>> for SBV<'a> {
-  fn try_insert(&mut self, k: usize, v: SBV<'a>) -> Result<(), InsertError> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: SBV<'a>) -> Result<(), CollectionError> {
     match self {
-      SBV::Array(a) => {
-        if a.len() >= k {
-          a.insert(k, v);
-          Ok(())
+      SBV::Array(a) => <Vec<SBV<'a>> as TryInsert<usize, SBV<'a>>>::try_insert(a, k, v),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'a> TryInsert<Cow<'a, str>, SBV<'a>> for SBV<'a> {
+  /// `halfbrown::HashMap` provides no fallible way of growing its storage
+  type Safety = Unsafe;
+  fn try_insert(&mut self, k: Cow<'a, str>, v: SBV<'a>) -> Result<(), CollectionError> {
+    match self {
+      SBV::Object(o) => {
+        o.insert(k, v);
+        Ok(())
+      }
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl TryInsert<usize, SOV> for SOV {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: SOV) -> Result<(), CollectionError> {
+    match self {
+      SOV::Array(a) => <Vec<SOV> as TryInsert<usize, SOV>>::try_insert(a, k, v),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl TryInsert<String, SOV> for SOV {
+  /// `halfbrown::HashMap` provides no fallible way of growing its storage
+  type Safety = Unsafe;
+  fn try_insert(&mut self, k: String, v: SOV) -> Result<(), CollectionError> {
+    match self {
+      SOV::Object(o) => {
+        o.insert(k, v);
+        Ok(())
+      }
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> TryInsert<usize, V> for slab::Slab<V> {
+  type Safety = Safe;
+  /// Overwrites the value at an occupied key or fills the next vacant key if that needs no allocation, as `Slab` provides no fallible way of growing its storage.
+  /// Reports `CapacityExceeded` if the slab is full and `KeyNotFound` for any other key.
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    if let Some(e) = slab::Slab::get_mut(self, k) {
+      *e = v;
+      Ok(())
+    } else if k != self.vacant_key() {
+      Err(CollectionError::KeyNotFound)
+    } else if k < self.capacity() {
+      self.insert(v);
+      Ok(())
+    } else {
+      Err(CollectionError::CapacityExceeded)
+    }
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> TryInsert<usize, A::Item> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: A::Item) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      <Self as TryReserve>::try_reserve(self, 1)?;
+      self.insert(k, v);
+      Ok(())
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryInsert<K, V> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.insert(k, v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> TryInsert<K, ()> for IndexSet<K, S> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.insert(k);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryInsert<K, V> for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    hashbrown::HashMap::insert(self, k, v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> TryInsert<K, ()> for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, _v: ()) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    hashbrown::HashSet::insert(self, k);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> TryInsert<usize, V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      arrayvec::ArrayVec::try_insert(self, k, v).map_err(|_| CollectionError::CapacityExceeded)
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryInsert<usize, V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: V) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      heapless::Vec::insert(self, k, v).map_err(|_| CollectionError::CapacityExceeded)
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> TryInsert<K, V>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  fn try_insert(&mut self, k: K, v: V) -> Result<(), CollectionError> {
+    heapless::IndexMap::insert(self, k, v)
+      .map(|_| ())
+      .map_err(|_| CollectionError::CapacityExceeded)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TryInsert<usize, A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: A::Item) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      match tinyvec::ArrayVec::try_insert(self, k, v) {
+        None => Ok(()),
+        Some(_) => Err(CollectionError::CapacityExceeded),
+      }
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> TryInsert<usize, A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn try_insert(&mut self, k: usize, v: A::Item) -> Result<(), CollectionError> {
+    if k <= self.len() {
+      <Self as TryReserve>::try_reserve(self, 1)?;
+      tinyvec::TinyVec::insert(self, k, v);
+      Ok(())
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  use crate::Get;
+  use core::fmt::Debug;
+  use proptest::prelude::*;
+  use std::hash::{BuildHasherDefault, DefaultHasher};
+
+  /// Checks that a successful insert is visible through `Get` and a failed one leaves the collection untouched.
+  fn agrees_with_get<C, K, V>(c: &mut C, k: K, v: V, ok: bool) -> Result<(), TestCaseError>
+  where
+    C: Clone + Debug + PartialEq + TryInsert<K, V> + for<'k, 'a> Get<&'k K, Value<'a> = &'a V>,
+    K: Clone,
+    V: Clone + Debug + PartialEq,
+  {
+    let before = c.clone();
+    let r = c.try_insert(k.clone(), v.clone());
+    prop_assert_eq!(r.is_ok(), ok);
+    if ok {
+      prop_assert_eq!(c.get(&k), Some(&v));
+    } else {
+      prop_assert_eq!(&*c, &before);
+    }
+    Ok(())
+  }
+
+  proptest! {
+    #[test]
+    fn array(mut a: [i32; 4], k in 0..8usize, v: i32) {
+      agrees_with_get(&mut a, k, v, k < 4)?;
+    }
+
+    #[test]
+    fn slice(mut a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let len = a.len();
+      let s = &mut a[..];
+      let r = <[i32] as TryInsert<usize, i32>>::try_insert(s, k, v);
+      if k < len {
+        prop_assert_eq!(r, Ok(()));
+        prop_assert_eq!(<[i32] as Get<usize>>::get(s, k), Some(&v));
+      } else {
+        prop_assert_eq!(r, Err(CollectionError::OutOfBounds { index: k, len }));
+      }
+    }
+
+    #[test]
+    fn vec(mut a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let ok = k <= a.len();
+      agrees_with_get(&mut a, k, v, ok)?;
+    }
+
+    #[test]
+    fn vec_deque(a in prop::collection::vec_deque(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let ok = k <= a.len();
+      agrees_with_get(&mut a.clone(), k, v, ok)?;
+    }
+
+    #[test]
+    fn linked_list(a in prop::collection::linked_list(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let ok = k <= a.len();
+      agrees_with_get(&mut a.clone(), k, v, ok)?;
+    }
+
+    #[test]
+    fn btree_map(mut m in prop::collection::btree_map(any::<i8>(), any::<i32>(), 0..8), k: i8, v: i32) {
+      agrees_with_get(&mut m, k, v, true)?;
+    }
+
+    #[test]
+    fn hash_map(mut m in prop::collection::hash_map(any::<i8>(), any::<i32>(), 0..8), k: i8, v: i32) {
+      agrees_with_get(&mut m, k, v, true)?;
+    }
+
+    #[test]
+    fn hash_map_custom_hasher(entries in prop::collection::vec((any::<i8>(), any::<i32>()), 0..8), k: i8, v: i32) {
+      let mut m: HashMap<i8, i32, BuildHasherDefault<DefaultHasher>> = entries.into_iter().collect();
+      agrees_with_get(&mut m, k, v, true)?;
+    }
+
+    #[test]
+    fn btree_set(mut s in prop::collection::btree_set(any::<i8>(), 0..8), k: i8) {
+      prop_assert_eq!(s.try_insert(k, ()), Ok(()));
+      prop_assert_eq!(<BTreeSet<i8> as Get<&i8>>::get(&s, &k), Some(&k));
+    }
+
+    #[test]
+    fn hash_set(mut s in prop::collection::hash_set(any::<i8>(), 0..8), k: i8) {
+      prop_assert_eq!(s.try_insert(k, ()), Ok(()));
+      prop_assert_eq!(<HashSet<i8> as Get<&i8>>::get(&s, &k), Some(&k));
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn dashmap(entries in prop::collection::vec((any::<i8>(), any::<i32>()), 0..8), k: i8, v: i32) {
+      let mut m: DashMap<i8, i32> = entries.iter().copied().collect();
+      prop_assert_eq!(m.try_insert(k, v), Ok(()));
+      prop_assert_eq!(<DashMap<i8, i32> as Get<&i8>>::get(&m, &k).map(|v| *v), Some(v));
+      let mut s: DashSet<i8> = entries.into_iter().map(|(k, _)| k).collect();
+      prop_assert_eq!(s.try_insert(k, ()), Ok(()));
+      prop_assert_eq!(<DashSet<i8> as Get<&i8>>::get(&s, &k).map(|k| *k), Some(k));
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+  use serde_json::json;
+  #[test]
+  fn serde_json() {
+    let mut a = json!([0]);
+    assert_eq!(
+      <SeV as TryInsert<usize, SeV>>::try_insert(&mut a, 3, json!(1)),
+      Err(CollectionError::OutOfBounds { index: 3, len: 1 })
+    );
+    let mut o = json!({});
+    assert_eq!(
+      <SeV as TryInsert<usize, SeV>>::try_insert(&mut o, 0, json!(1)),
+      Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: "object"
+      })
+    );
+    assert_eq!(
+      <SeV as TryInsert<String, SeV>>::try_insert(&mut a, "A".into(), json!(1)),
+      Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: "array"
+      })
+    );
+  }
+
+  proptest! {
+    #[test]
+    fn array(a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let ok = k <= a.len();
+      let mut a = json!(a);
+      let before = a.clone();
+      let r = <SeV as TryInsert<usize, SeV>>::try_insert(&mut a, k, json!(v));
+      prop_assert_eq!(r.is_ok(), ok);
+      if ok {
+        prop_assert_eq!(<SeV as Get<usize>>::get(&a, k), Some(&json!(v)));
+      } else {
+        prop_assert_eq!(a, before);
+      }
+    }
+
+    #[test]
+    fn object(k: String, v: i32, array: bool) {
+      let mut o = if array { json!([]) } else { json!({}) };
+      let r = <SeV as TryInsert<String, SeV>>::try_insert(&mut o, k.clone(), json!(v));
+      prop_assert_eq!(r.is_ok(), !array);
+      if !array {
+        prop_assert_eq!(<SeV as Get<&str>>::get(&o, &k), Some(&json!(v)));
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "simd-json")]
+mod simd_json_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn borrowed(a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let ok = k <= a.len();
+      let mut a = SBV::Array(a.into_iter().map(SBV::from).collect());
+      let before = a.clone();
+      let r = <SBV as TryInsert<usize, SBV>>::try_insert(&mut a, k, SBV::from(v));
+      prop_assert_eq!(r.is_ok(), ok);
+      if ok {
+        prop_assert_eq!(<SBV as Get<usize>>::get(&a, k), Some(&SBV::from(v)));
+      } else {
+        prop_assert_eq!(a, before);
+      }
+    }
+
+    #[test]
+    fn borrowed_object(k: String, v: i32, array: bool) {
+      let mut o = if array { SBV::Array(vec![]) } else { SBV::Object(Default::default()) };
+      let r = <SBV as TryInsert<Cow<str>, SBV>>::try_insert(&mut o, Cow::from(k.clone()), SBV::from(v));
+      prop_assert_eq!(r.is_ok(), !array);
+      if !array {
+        prop_assert_eq!(<SBV as Get<&str>>::get(&o, &k), Some(&SBV::from(v)));
+      }
+    }
+
+    #[test]
+    fn owned(a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let ok = k <= a.len();
+      let mut a = SOV::Array(a.into_iter().map(SOV::from).collect());
+      let before = a.clone();
+      let r = <SOV as TryInsert<usize, SOV>>::try_insert(&mut a, k, SOV::from(v));
+      prop_assert_eq!(r.is_ok(), ok);
+      if ok {
+        prop_assert_eq!(<SOV as Get<usize>>::get(&a, k), Some(&SOV::from(v)));
+      } else {
+        prop_assert_eq!(a, before);
+      }
+    }
+
+    #[test]
+    fn owned_object(k: String, v: i32, array: bool) {
+      let mut o = if array { SOV::Array(vec![]) } else { SOV::Object(Default::default()) };
+      let r = <SOV as TryInsert<String, SOV>>::try_insert(&mut o, k.clone(), SOV::from(v));
+      prop_assert_eq!(
+        r,
+        if array {
+          Err(CollectionError::WrongValueKind { expected: "object", found: "array" })
         } else {
-          Err(InsertError::OutOfBounds)
+          Ok(())
         }
+      );
+      if !array {
+        prop_assert_eq!(<SOV as Get<&str>>::get(&o, &k), Some(&SOV::from(v)));
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn slab(n in 0..8usize, removed in 0..8usize, spare in 0..2usize, k in 0..16usize, v: i32) {
+      let mut s: slab::Slab<i32> = (0..n).map(|i| (i, 0)).collect();
+      if s.contains(removed) {
+        s.remove(removed);
       }
-      _ => Err(InsertError::UnsupportedContainerType),
-    }
-  }
-}
-
-// #[cfg(feature = "simd-json")]
-// impl<'a> Insert<Cow<'a, str>, SBV<'a>> for SBV<'a> {
-//   type Safety = Unsafe;
-//   fn insert(&mut self, k: Cow<'a, str>, v: SBV<'a>) {
-//     match self {
-//       SBV::Object(o) => {
-//         o.insert(k, v);
-//       }
-//       _ => panic!("Value is not an object"),
-//     }
-//   }
-// }
-
-// // #[cfg(feature = "simd-json")]
-// // impl Insert<usize, SOV> for SOV {
-// //   type Safety = Unsafe;
-// //   fn insert(&mut self, k: usize, v: SOV) {
-// //     match self {
-// //       SOV::Array(a) => {
-// //         a.insert(k, v);
-// //       }
-// //       _ => panic!("Value is not an array"),
-// //     }
-// //   }
-// // }
-
-// // #[cfg(feature = "simd-json")]
-// // impl Insert<String, SOV> for SOV {
-// //   type Safety = Safe;
-// //   fn insert(&mut self, k: String, v: SOV) {
-// //     match self {
-// //       SOV::Object(o) => {
-// //         o.insert(k, v);
-// //       }
-// //       _ => panic!("Value is not an object"),
-// //     }
-// //   }
-// // }
-
-// // #[cfg(feature = "smallvec")]
-// // use smallvec::{Array, SmallVec};
-
-// // #[cfg(feature = "smallvec")]
-// // impl<V, A: Array<Item = V>> Insert<usize, V> for SmallVec<A> {
-// //   type Safety = Unsafe;
-// //   fn insert(&mut self, k: usize, v: V) {
-// //     self.insert(k, v)
-// //   }
-// // }
-
-// // #[cfg(test)]
-// // mod tests {
-// //   use super::*;
-// //   use crate::Get;
-// //   #[test]
-// //   fn std() {
-// //     let mut v = [0, 1, 2];
-// //     v.insert(0, 1);
-// //     assert_eq!(v[0], 1);
-// //     let v = &mut [0, 1, 2][..];
-// //     v.insert(0, 1);
-// //     assert_eq!(v[0], 1);
-// //     let mut v = vec![];
-// //     <Vec<i32> as Insert<usize, i32>>::insert(&mut v, 0, 1);
-// //     assert_eq!(v[0], 1);
-// //     let mut v = VecDeque::new();
-// //     <VecDeque<i32> as Insert<usize, i32>>::insert(&mut v, 0, 1);
-// //     assert_eq!(v[0], 1);
-// //     let mut v = LinkedList::new();
-// //     <LinkedList<i32> as Insert<usize, i32>>::insert(&mut v, 0, 1);
-// //     <LinkedList<i32> as Insert<usize, i32>>::insert(&mut v, 1, 2);
-// //     <LinkedList<i32> as Insert<usize, i32>>::insert(&mut v, 1, 3);
-// //     assert_eq!(v.get(0), Some(&1));
-// //     assert_eq!(v.get(1), Some(&3));
-// //     assert_eq!(v.get(2), Some(&2));
-// //     let mut m = BTreeMap::new();
-// //     <BTreeMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 0, 1);
-// //     assert_eq!(m[&0], 1);
-// //     let mut s = BTreeSet::new();
-// //     <BTreeSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
-// //     assert_eq!(s.get(&0), Some(&0));
-// //     let mut m = HashMap::new();
-// //     <HashMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 0, 1);
-// //     assert_eq!(m[&0], 1);
-// //     let mut s = HashSet::new();
-// //     <HashSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
-// //     assert_eq!(s.get(&0), Some(&0));
-// //   }
-// // }
-
-// // #[cfg(test)]
-// // #[cfg(feature = "dashmap")]
-// // mod dashmap_tests {
-// //   use super::*;
-// //   #[test]
-// //   fn dashmap() {
-// //     let mut m = DashMap::new();
-// //     <DashMap<i32, i32> as Insert<i32, i32>>::insert(&mut m, 0, 1);
-// //     assert_eq!(m.get(&0).map(|v| v.clone()), Some(1));
-// //     let mut s = DashSet::new();
-// //     <DashSet<i32> as Insert<i32, ()>>::insert(&mut s, 0, ());
-// //     assert_eq!(s.get(&0).map(|v| v.clone()), Some(0));
-// //   }
-// // }
-
-// // #[cfg(test)]
-// // #[cfg(feature = "serde_json")]
-// // mod serde_json_tests {
-// //   use super::*;
-// //   #[test]
-// //   fn serde_json() {
-// //     let mut a = SeV::Array(Default::default());
-// //     <SeV as Insert<usize, SeV>>::insert(&mut a, 0, SeV::Null);
-// //     assert_eq!(a.get(0), Some(&SeV::Null));
-// //     let mut o = SeV::Object(Default::default());
-// //     <SeV as Insert<String, SeV>>::insert(&mut o, "a".into(), SeV::Null);
-// //     assert_eq!(o.get("a"), Some(&SeV::Null));
-// //   }
-// // }
-
-// // #[cfg(test)]
-// // #[cfg(feature = "simd-json")]
-// // mod simd_json_tests {
-// //   use super::*;
-// //   use crate::Get;
-// //   #[test]
-// //   fn simd_json_borrowed() {
-// //     let mut a = SBV::Array(Default::default());
-// //     <SBV as Insert<usize, SBV>>::insert(&mut a, 0_usize, SBV::Static(simd_json::StaticNode::Null));
-// //     assert_eq!(
-// //       <simd_json::BorrowedValue as Get<usize>>::get(&a, 0),
-// //       Some(&SBV::Static(simd_json::StaticNode::Null))
-// //     );
-// //     let mut o = SBV::Object(Default::default());
-// //     <SBV as Insert<Cow<'_, str>, SBV>>::insert(
-// //       &mut o,
-// //       "a".into(),
-// //       SBV::Static(simd_json::StaticNode::Null),
-// //     );
-// //     assert_eq!(o.get("a"), Some(&SBV::Static(simd_json::StaticNode::Null)));
-// //   }
-// //   #[test]
-// //   fn simd_json_owned() {
-// //     let mut a = SOV::Array(Default::default());
-// //     <SOV as Insert<usize, SOV>>::insert(&mut a, 0, SOV::Static(simd_json::StaticNode::Null));
-// //     assert_eq!(
-// //       <simd_json::OwnedValue as Get<usize>>::get(&a, 0),
-// //       Some(&SOV::Static(simd_json::StaticNode::Null))
-// //     );
-// //     let mut o = SOV::Object(Default::default());
-// //     <SOV as Insert<String, SOV>>::insert(
-// //       &mut o,
-// //       "a".into(),
-// //       SOV::Static(simd_json::StaticNode::Null),
-// //     );
-// //     assert_eq!(o.get("a"), Some(&SOV::Static(simd_json::StaticNode::Null)));
-// //   }
-// // }
-
-// // #[cfg(test)]
-// // #[cfg(feature = "smallvec")]
-// // mod smallvec_tests {
-// //   use super::*;
-// //   #[test]
-// //   fn smallvec() {
-// //     let mut sv = smallvec::SmallVec::<[i32; 2]>::new();
-// //     <SmallVec<[i32; 2]> as Insert<usize, i32>>::insert(&mut sv, 0, 1);
-// //     assert_eq!(sv[0], 1);
-// //   }
-// // }
+      s.reserve_exact(spare);
+      let capacity = s.capacity();
+      let vacant = k == s.vacant_key();
+      let r = s.try_insert(k, v);
+      if s.contains(k) || (vacant && k < capacity) {
+        prop_assert_eq!(r, Ok(()));
+        prop_assert_eq!(<slab::Slab<i32> as Get<usize>>::get(&s, k), Some(&v));
+      } else if vacant {
+        prop_assert_eq!(r, Err(CollectionError::CapacityExceeded));
+      } else {
+        prop_assert_eq!(r, Err(CollectionError::KeyNotFound));
+      }
+      prop_assert_eq!(s.capacity(), capacity);
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+  use smallvec::SmallVec;
+
+  proptest! {
+    #[test]
+    fn smallvec(a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      let mut sv = SmallVec::<[i32; 4]>::from_vec(a);
+      let before = sv.clone();
+      let r = sv.try_insert(k, v);
+      prop_assert_eq!(r.is_ok(), k <= before.len());
+      if r.is_ok() {
+        prop_assert_eq!(<SmallVec<[i32; 4]> as Get<usize>>::get(&sv, k), Some(&v));
+      } else {
+        prop_assert_eq!(sv, before);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn index_map(entries in prop::collection::vec((any::<i8>(), any::<i32>()), 0..8), k: i8, v: i32) {
+      let mut m: IndexMap<i8, i32> = entries.into_iter().collect();
+      let position = m.get_index_of(&k).unwrap_or(m.len());
+      prop_assert_eq!(m.try_insert(k, v), Ok(()));
+      prop_assert_eq!(<IndexMap<i8, i32> as Get<&i8>>::get(&m, &k), Some(&v));
+      prop_assert_eq!(m.get_index_of(&k), Some(position));
+    }
+
+    #[test]
+    fn index_set(keys in prop::collection::vec(any::<i8>(), 0..8), k: i8) {
+      let mut s: IndexSet<i8> = keys.into_iter().collect();
+      prop_assert_eq!(s.try_insert(k, ()), Ok(()));
+      prop_assert_eq!(<IndexSet<i8> as Get<&i8>>::get(&s, &k), Some(&k));
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  use crate::Get;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn hash_map(entries in prop::collection::vec((any::<i8>(), any::<i32>()), 0..8), k: i8, v: i32) {
+      let mut m: hashbrown::HashMap<i8, i32> = entries.into_iter().collect();
+      prop_assert_eq!(TryInsert::try_insert(&mut m, k, v), Ok(()));
+      prop_assert_eq!(<hashbrown::HashMap<i8, i32> as Get<&i8>>::get(&m, &k), Some(&v));
+    }
+
+    #[test]
+    fn hash_set(keys in prop::collection::vec(any::<i8>(), 0..8), k: i8) {
+      let mut s: hashbrown::HashSet<i8> = keys.into_iter().collect();
+      prop_assert_eq!(TryInsert::try_insert(&mut s, k, ()), Ok(()));
+      prop_assert_eq!(<hashbrown::HashSet<i8> as Get<&i8>>::get(&s, &k), Some(&k));
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(any(feature = "arrayvec", feature = "heapless", feature = "tinyvec"))]
+mod fixed_capacity_tests {
+  use super::*;
+
+  /// Checks that `try_insert` into a collection with room for `capacity` values succeeds up to its length and otherwise reports why it failed, leaving the collection untouched.
+  pub(super) fn agrees_with_capacity<C>(
+    c: &mut C,
+    capacity: usize,
+    k: usize,
+    v: i32,
+  ) -> Result<(), proptest::test_runner::TestCaseError>
+  where
+    C: Clone
+      + core::fmt::Debug
+      + PartialEq
+      + TryInsert<usize, i32>
+      + crate::Len
+      + for<'a> crate::Get<usize, Value<'a> = &'a i32>,
+  {
+    use proptest::prop_assert_eq;
+    let before = c.clone();
+    let len = crate::Len::len(&before);
+    let r = c.try_insert(k, v);
+    if k <= len && len < capacity {
+      prop_assert_eq!(r, Ok(()));
+      prop_assert_eq!(c.get(k), Some(&v));
+    } else {
+      let e = if k > len {
+        CollectionError::OutOfBounds { index: k, len }
+      } else {
+        CollectionError::CapacityExceeded
+      };
+      prop_assert_eq!(r, Err(e));
+      prop_assert_eq!(&*c, &before);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::fixed_capacity_tests::agrees_with_capacity;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn arrayvec(a in prop::collection::vec(any::<i32>(), 0..=4), k in 0..8usize, v: i32) {
+      agrees_with_capacity(&mut arrayvec::ArrayVec::<i32, 4>::from_iter(a), 4, k, v)?;
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::fixed_capacity_tests::agrees_with_capacity;
+  use super::*;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn heapless(a in prop::collection::vec(any::<i32>(), 0..=4), k in 0..8usize, v: i32) {
+      agrees_with_capacity(&mut heapless::Vec::<i32, 4>::from_iter(a), 4, k, v)?;
+    }
+  }
+
+  #[test]
+  fn heapless_index_map() {
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::from_iter([(0, 0)]);
+    assert_eq!(TryInsert::try_insert(&mut m, 1, 1), Ok(()));
+    assert_eq!(TryInsert::try_insert(&mut m, 1, 2), Ok(()));
+    assert_eq!(
+      TryInsert::try_insert(&mut m, 2, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    assert_eq!(m.get(&1), Some(&2));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::fixed_capacity_tests::agrees_with_capacity;
+  use proptest::prelude::*;
+
+  proptest! {
+    #[test]
+    fn tinyvec(a in prop::collection::vec(any::<i32>(), 0..=4), k in 0..8usize, v: i32) {
+      agrees_with_capacity(&mut tinyvec::ArrayVec::<[i32; 4]>::from_iter(a), 4, k, v)?;
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn tiny_vec(a in prop::collection::vec(any::<i32>(), 0..8), k in 0..16usize, v: i32) {
+      agrees_with_capacity(&mut tinyvec::TinyVec::<[i32; 4]>::from_iter(a), usize::MAX, k, v)?;
+    }
+  }
+}
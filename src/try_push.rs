@@ -0,0 +1,399 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "slab",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+#[cfg(feature = "alloc")]
+use crate::TryReserve;
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+use crate::ValueKind;
+use crate::{CollectionError, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BinaryHeap, LinkedList, VecDeque},
+  vec::Vec,
+};
+
+/// Provides the ability to add a specified value to a collection, reporting failure instead of panicking.
+///
+/// Capacity is reserved up front, so collections backed by a growable buffer report `CapacityExceeded` or `AllocationFailed` rather than aborting, and JSON values that aren't arrays report `WrongValueKind`.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use collectivity::{CollectionError, TryPush};
+///
+/// let mut v = vec![];
+/// assert_eq!(v.try_push(1), Ok(()));
+/// assert_eq!(v, vec![1]);
+/// # }
+/// ```
+pub trait TryPush<V> {
+  /// Indicates whether the `try_push` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Adds the provided value to the collection, or returns the reason it can't be added.
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError>;
+}
+
+impl<V, P: TryPush<V>> TryPush<V> for &mut P {
+  type Safety = <P as TryPush<V>>::Safety;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    <P as TryPush<V>>::try_push(self, v)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryPush<V> for Vec<V> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.push(v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryPush<V> for VecDeque<V> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.push_back(v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> TryPush<V> for BinaryHeap<V> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.push(v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryPush<V> for LinkedList<V> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    self.push_back(v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl TryPush<SeV> for SeV {
+  type Safety = Safe;
+  fn try_push(&mut self, v: SeV) -> Result<(), CollectionError> {
+    match self {
+      SeV::Array(a) => a.try_push(v),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'a> TryPush<SBV<'a>> for SBV<'a> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: SBV<'a>) -> Result<(), CollectionError> {
+    match self {
+      SBV::Array(a) => a.try_push(v),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl TryPush<SOV> for SOV {
+  type Safety = Safe;
+  fn try_push(&mut self, v: SOV) -> Result<(), CollectionError> {
+    match self {
+      SOV::Array(a) => a.try_push(v),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> TryPush<V> for slab::Slab<V> {
+  type Safety = Safe;
+  /// Fills the next vacant key, reporting `CapacityExceeded` if that would need an allocation
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    let k = self.vacant_key();
+    <Self as crate::TryInsert<usize, V>>::try_insert(self, k, v)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<V, A: smallvec::Array<Item = V>> TryPush<V> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.push(v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher> TryPush<(K, V)>
+  for IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  fn try_push(&mut self, (k, v): (K, V)) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.insert(k, v);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + core::hash::Hash, S: core::hash::BuildHasher> TryPush<K> for IndexSet<K, S> {
+  type Safety = Safe;
+  fn try_push(&mut self, k: K) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    self.insert(k);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> TryPush<V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    arrayvec::ArrayVec::try_push(self, v).map_err(|_| CollectionError::CapacityExceeded)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryPush<V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    heapless::Vec::push(self, v).map_err(|_| CollectionError::CapacityExceeded)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryPush<V> for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    heapless::Deque::push_back(self, v).map_err(|_| CollectionError::CapacityExceeded)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> TryPush<V>
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  fn try_push(&mut self, v: V) -> Result<(), CollectionError> {
+    heapless::BinaryHeap::push(self, v).map_err(|_| CollectionError::CapacityExceeded)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + core::hash::Hash, V, S: core::hash::BuildHasher, const N: usize> TryPush<(K, V)>
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  /// Appends a new key, or replaces the value of an existing key in place
+  fn try_push(&mut self, (k, v): (K, V)) -> Result<(), CollectionError> {
+    heapless::IndexMap::insert(self, k, v)
+      .map(|_| ())
+      .map_err(|_| CollectionError::CapacityExceeded)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TryPush<A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: A::Item) -> Result<(), CollectionError> {
+    match tinyvec::ArrayVec::try_push(self, v) {
+      None => Ok(()),
+      Some(_) => Err(CollectionError::CapacityExceeded),
+    }
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> TryPush<A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn try_push(&mut self, v: A::Item) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, 1)?;
+    tinyvec::TinyVec::push(self, v);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut h = BinaryHeap::new();
+    assert_eq!(h.try_push(1), Ok(()));
+    assert_eq!(h.peek(), Some(&1));
+    let mut l = LinkedList::new();
+    assert_eq!(l.try_push(1), Ok(()));
+    assert_eq!(l.back(), Some(&1));
+    let mut v = Vec::<()>::new();
+    <Vec<()> as TryReserve>::try_reserve(&mut v, 1).unwrap();
+    assert_eq!(v.try_push(()), Ok(()));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  use serde_json::json;
+  #[test]
+  fn serde_json() {
+    let mut a = json!([]);
+    assert_eq!(a.try_push(json!(1)), Ok(()));
+    assert_eq!(a, json!([1]));
+    let mut n = json!(null);
+    assert_eq!(
+      n.try_push(json!(1)),
+      Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: "null"
+      })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = smallvec::SmallVec::<[i32; 1]>::new();
+    assert_eq!(sv.try_push(1), Ok(()));
+    assert_eq!(sv.try_push(2), Ok(()));
+    assert_eq!(&sv[..], &[1, 2]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = IndexMap::new();
+    assert_eq!(m.try_push((1, 0)), Ok(()));
+    assert_eq!(m.try_push((0, 1)), Ok(()));
+    assert_eq!(m.last(), Some((&0, &1)));
+    let mut s = IndexSet::new();
+    assert_eq!(s.try_push(1), Ok(()));
+    assert_eq!(s.try_push(0), Ok(()));
+    assert_eq!(s.iter().collect::<Vec<_>>(), [&1, &0]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::<i32>::with_capacity(1);
+    assert_eq!(TryPush::try_push(&mut s, 1), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut s, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    s.remove(0);
+    assert_eq!(TryPush::try_push(&mut s, 3), Ok(()));
+    assert_eq!(s.get(0), Some(&3));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 1>::new();
+    assert_eq!(TryPush::try_push(&mut v, 1), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut v, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    assert_eq!(v.as_slice(), &[1]);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut v = heapless::Vec::<i32, 1>::new();
+    assert_eq!(TryPush::try_push(&mut v, 1), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut v, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    let mut d = heapless::Deque::<i32, 1>::new();
+    assert_eq!(TryPush::try_push(&mut d, 1), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut d, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    let mut h = heapless::BinaryHeap::<i32, heapless::binary_heap::Max, 1>::new();
+    assert_eq!(TryPush::try_push(&mut h, 1), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut h, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::from_iter([(0, 0)]);
+    assert_eq!(TryPush::try_push(&mut m, (1, 1)), Ok(()));
+    assert_eq!(TryPush::try_push(&mut m, (1, 2)), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut m, (2, 2)),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 1]>::new();
+    assert_eq!(TryPush::try_push(&mut v, 1), Ok(()));
+    assert_eq!(
+      TryPush::try_push(&mut v, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    assert_eq!(v.as_slice(), &[1]);
+  }
+}
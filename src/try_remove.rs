@@ -0,0 +1,670 @@
+#[cfg(any(
+  feature = "alloc",
+  feature = "arrayvec",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+use crate::Safe;
+#[cfg(any(feature = "serde_json", feature = "simd-json"))]
+use crate::ValueKind;
+use crate::{CollectionError, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::{
+  collections::{BTreeMap, BTreeSet, LinkedList, VecDeque},
+  vec::Vec,
+};
+#[cfg(any(feature = "alloc", feature = "heapless"))]
+use core::borrow::Borrow;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+/// Provides the ability to remove a value by key, reporting why nothing could be removed.
+///
+/// Indexed collections report `OutOfBounds`, keyed collections report `KeyNotFound`, and JSON values of the wrong kind report `WrongValueKind`.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use collectivity::{CollectionError, TryRemove};
+///
+/// let mut v = vec![1, 2];
+/// assert_eq!(v.try_remove(0), Ok(1));
+/// assert_eq!(
+///   v.try_remove(1),
+///   Err(CollectionError::OutOfBounds { index: 1, len: 1 })
+/// );
+/// # }
+/// ```
+pub trait TryRemove<K, V> {
+  /// Indicates whether the `try_remove` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Removes and returns the value at `k`, or returns the reason it can't be removed without affecting the collection.
+  fn try_remove(&mut self, k: K) -> Result<V, CollectionError>;
+}
+
+impl<K, V, R: TryRemove<K, V>> TryRemove<K, V> for &mut R {
+  type Safety = <R as TryRemove<K, V>>::Safety;
+  fn try_remove(&mut self, k: K) -> Result<V, CollectionError> {
+    <R as TryRemove<K, V>>::try_remove(self, k)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryRemove<usize, V> for Vec<V> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    if k < self.len() {
+      Ok(self.remove(k))
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryRemove<usize, V> for VecDeque<V> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    let len = self.len();
+    self
+      .remove(k)
+      .ok_or(CollectionError::OutOfBounds { index: k, len })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryRemove<usize, V> for LinkedList<V> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    let len = self.len();
+    if k < len {
+      let mut rest = self.split_off(k);
+      let v = rest.pop_front();
+      self.append(&mut rest);
+      if let Some(v) = v {
+        return Ok(v);
+      }
+    }
+    Err(CollectionError::OutOfBounds { index: k, len })
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord, V> TryRemove<&'k Q, V> for BTreeMap<K, V> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<V, CollectionError> {
+    self.remove(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<'k, Q: Ord + ?Sized, K: Borrow<Q> + Ord> TryRemove<&'k Q, ()> for BTreeSet<K> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<(), CollectionError> {
+    if self.remove(k) {
+      Ok(())
+    } else {
+      Err(CollectionError::KeyNotFound)
+    }
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> TryRemove<&'k Q, V>
+  for HashMap<K, V, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<V, CollectionError> {
+    self.remove(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryRemove<&'k Q, ()>
+  for HashSet<K, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<(), CollectionError> {
+    if self.remove(k) {
+      Ok(())
+    } else {
+      Err(CollectionError::KeyNotFound)
+    }
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher + Clone>
+  TryRemove<&'k Q, V> for dashmap::DashMap<K, V, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<V, CollectionError> {
+    dashmap::DashMap::remove(self, k)
+      .map(|v| v.1)
+      .ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher + Clone>
+  TryRemove<&'k Q, K> for dashmap::DashSet<K, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<K, CollectionError> {
+    dashmap::DashSet::remove(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+use serde_json::Value as SeV;
+
+#[cfg(feature = "serde_json")]
+impl<'k> TryRemove<&'k str, SeV> for SeV {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k str) -> Result<SeV, CollectionError> {
+    match self {
+      SeV::Object(o) => o.remove(k).ok_or(CollectionError::KeyNotFound),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl TryRemove<usize, SeV> for SeV {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<SeV, CollectionError> {
+    match self {
+      SeV::Array(a) => <Vec<SeV> as TryRemove<usize, SeV>>::try_remove(a, k),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+use simd_json::{BorrowedValue as SBV, OwnedValue as SOV};
+
+#[cfg(feature = "simd-json")]
+impl<'k, 'a> TryRemove<&'k str, SBV<'a>> for SBV<'a> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k str) -> Result<SBV<'a>, CollectionError> {
+    match self {
+      SBV::Object(o) => o.remove(k).ok_or(CollectionError::KeyNotFound),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'a> TryRemove<usize, SBV<'a>> for SBV<'a> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<SBV<'a>, CollectionError> {
+    match self {
+      SBV::Array(a) => <Vec<SBV<'a>> as TryRemove<usize, SBV<'a>>>::try_remove(a, k),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'k> TryRemove<&'k str, SOV> for SOV {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k str) -> Result<SOV, CollectionError> {
+    match self {
+      SOV::Object(o) => o.remove(k).ok_or(CollectionError::KeyNotFound),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "object",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl TryRemove<usize, SOV> for SOV {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<SOV, CollectionError> {
+    match self {
+      SOV::Array(a) => <Vec<SOV> as TryRemove<usize, SOV>>::try_remove(a, k),
+      _ => Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: self.kind(),
+      }),
+    }
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> TryRemove<usize, V> for slab::Slab<V> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    slab::Slab::try_remove(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<V, A: smallvec::Array<Item = V>> TryRemove<usize, V> for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    if k < self.len() {
+      Ok(self.remove(k))
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "indexmap")]
+use crate::{Position, Swap};
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> TryRemove<&'k Q, V>
+  for IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  /// Shifts the following entries to preserve the insertion order
+  fn try_remove(&mut self, k: &'k Q) -> Result<V, CollectionError> {
+    self.shift_remove(k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher>
+  TryRemove<Swap<&'k Q>, V> for IndexMap<K, V, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: Swap<&'k Q>) -> Result<V, CollectionError> {
+    self.swap_remove(k.0).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryRemove<Position, (K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  /// Shifts the following entries to preserve the insertion order
+  fn try_remove(&mut self, k: Position) -> Result<(K, V), CollectionError> {
+    let len = self.len();
+    self
+      .shift_remove_index(k.0)
+      .ok_or(CollectionError::OutOfBounds { index: k.0, len })
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryRemove<Swap<Position>, (K, V)> for IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: Swap<Position>) -> Result<(K, V), CollectionError> {
+    let len = self.len();
+    self
+      .swap_remove_index(k.0 .0)
+      .ok_or(CollectionError::OutOfBounds { index: k.0 .0, len })
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryRemove<&'k Q, ()>
+  for IndexSet<K, S>
+{
+  type Safety = Safe;
+  /// Shifts the following values to preserve the insertion order
+  fn try_remove(&mut self, k: &'k Q) -> Result<(), CollectionError> {
+    if self.shift_remove(k) {
+      Ok(())
+    } else {
+      Err(CollectionError::KeyNotFound)
+    }
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryRemove<Swap<&'k Q>, ()>
+  for IndexSet<K, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: Swap<&'k Q>) -> Result<(), CollectionError> {
+    if self.swap_remove(k.0) {
+      Ok(())
+    } else {
+      Err(CollectionError::KeyNotFound)
+    }
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> TryRemove<Position, K> for IndexSet<K, S> {
+  type Safety = Safe;
+  /// Shifts the following values to preserve the insertion order
+  fn try_remove(&mut self, k: Position) -> Result<K, CollectionError> {
+    let len = self.len();
+    self
+      .shift_remove_index(k.0)
+      .ok_or(CollectionError::OutOfBounds { index: k.0, len })
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> TryRemove<Swap<Position>, K> for IndexSet<K, S> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: Swap<Position>) -> Result<K, CollectionError> {
+    let len = self.len();
+    self
+      .swap_remove_index(k.0 .0)
+      .ok_or(CollectionError::OutOfBounds { index: k.0 .0, len })
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher> TryRemove<&'k Q, V>
+  for hashbrown::HashMap<K, V, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<V, CollectionError> {
+    hashbrown::HashMap::remove(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, S: BuildHasher> TryRemove<&'k Q, ()>
+  for hashbrown::HashSet<K, S>
+{
+  type Safety = Safe;
+  fn try_remove(&mut self, k: &'k Q) -> Result<(), CollectionError> {
+    if hashbrown::HashSet::remove(self, k) {
+      Ok(())
+    } else {
+      Err(CollectionError::KeyNotFound)
+    }
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> TryRemove<usize, V> for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    if k < self.len() {
+      Ok(arrayvec::ArrayVec::remove(self, k))
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryRemove<usize, V> for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<V, CollectionError> {
+    if k < self.len() {
+      Ok(heapless::Vec::remove(self, k))
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<'k, Q: Eq + Hash + ?Sized, K: Borrow<Q> + Eq + Hash, V, S: BuildHasher, const N: usize>
+  TryRemove<&'k Q, V> for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  /// Moves the last entry into the position of the removed one
+  fn try_remove(&mut self, k: &'k Q) -> Result<V, CollectionError> {
+    heapless::IndexMap::remove(self, k).ok_or(CollectionError::KeyNotFound)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TryRemove<usize, A::Item> for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<A::Item, CollectionError> {
+    if k < self.len() {
+      Ok(tinyvec::ArrayVec::remove(self, k))
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> TryRemove<usize, A::Item> for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn try_remove(&mut self, k: usize) -> Result<A::Item, CollectionError> {
+    if k < self.len() {
+      Ok(tinyvec::TinyVec::remove(self, k))
+    } else {
+      Err(CollectionError::OutOfBounds {
+        index: k,
+        len: self.len(),
+      })
+    }
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    let mut v = VecDeque::from([1, 2]);
+    assert_eq!(
+      <VecDeque<i32> as TryRemove<usize, i32>>::try_remove(&mut v, 2),
+      Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+    );
+    let mut l = LinkedList::from([1, 2, 3]);
+    assert_eq!(
+      <LinkedList<i32> as TryRemove<usize, i32>>::try_remove(&mut l, 1),
+      Ok(2)
+    );
+    assert_eq!(l, LinkedList::from([1, 3]));
+    assert_eq!(
+      <LinkedList<i32> as TryRemove<usize, i32>>::try_remove(&mut l, 2),
+      Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+    );
+    let mut m = HashMap::from([(String::from("A"), 1)]);
+    assert_eq!(m.try_remove("A"), Ok(1));
+    assert_eq!(m.try_remove("A"), Err(CollectionError::KeyNotFound));
+    let mut s = BTreeSet::from([1]);
+    assert_eq!(s.try_remove(&1), Ok(()));
+    assert_eq!(s.try_remove(&1), Err(CollectionError::KeyNotFound));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  use serde_json::json;
+  #[test]
+  fn serde_json() {
+    let mut v = json!({"A": 1});
+    assert_eq!(v.try_remove("A"), Ok(json!(1)));
+    assert_eq!(v.try_remove("A"), Err(CollectionError::KeyNotFound));
+    assert_eq!(
+      v.try_remove(0),
+      Err(CollectionError::WrongValueKind {
+        expected: "array",
+        found: "object"
+      })
+    );
+    let mut a = json!([1]);
+    assert_eq!(
+      a.try_remove(1),
+      Err(CollectionError::OutOfBounds { index: 1, len: 1 })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    let k = s.insert(1);
+    assert_eq!(TryRemove::try_remove(&mut s, k), Ok(1));
+    assert_eq!(
+      TryRemove::try_remove(&mut s, k),
+      Err(CollectionError::KeyNotFound)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = smallvec::SmallVec::<[i32; 2]>::from_slice(&[1]);
+    assert_eq!(
+      sv.try_remove(1),
+      Err(CollectionError::OutOfBounds { index: 1, len: 1 })
+    );
+    assert_eq!(sv.try_remove(0), Ok(1));
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn index_map() {
+    let mut m = IndexMap::from([("A", 1), ("B", 2), ("C", 3), ("D", 4)]);
+    assert_eq!(TryRemove::try_remove(&mut m, &"A"), Ok(1));
+    assert_eq!(
+      TryRemove::try_remove(&mut m, &"A"),
+      Err(CollectionError::KeyNotFound)
+    );
+    assert_eq!(TryRemove::try_remove(&mut m, Swap(&"B")), Ok(2));
+    assert_eq!(m.keys().collect::<Vec<_>>(), [&"D", &"C"]);
+    assert_eq!(
+      TryRemove::<Position, _>::try_remove(&mut m, Position(2)),
+      Err(CollectionError::OutOfBounds { index: 2, len: 2 })
+    );
+    assert_eq!(
+      TryRemove::<Swap<Position>, _>::try_remove(&mut m, Swap(Position(0))),
+      Ok(("D", 4))
+    );
+    assert_eq!(
+      TryRemove::<Position, _>::try_remove(&mut m, Position(0)),
+      Ok(("C", 3))
+    );
+  }
+  #[test]
+  fn index_set() {
+    let mut s = IndexSet::from([1, 2, 3]);
+    assert_eq!(TryRemove::try_remove(&mut s, &1), Ok(()));
+    assert_eq!(
+      TryRemove::try_remove(&mut s, Swap(&1)),
+      Err(CollectionError::KeyNotFound)
+    );
+    assert_eq!(
+      TryRemove::<Swap<Position>, _>::try_remove(&mut s, Swap(Position(0))),
+      Ok(2)
+    );
+    assert_eq!(
+      TryRemove::<Position, _>::try_remove(&mut s, Position(1)),
+      Err(CollectionError::OutOfBounds { index: 1, len: 1 })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::from([(0, 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as TryRemove<&i32, i32>>::try_remove(&mut m, &0),
+      Ok(1)
+    );
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as TryRemove<&i32, i32>>::try_remove(&mut m, &0),
+      Err(CollectionError::KeyNotFound)
+    );
+    let mut s = hashbrown::HashSet::from([0]);
+    assert_eq!(
+      <hashbrown::HashSet<i32> as TryRemove<&i32, ()>>::try_remove(&mut s, &0),
+      Ok(())
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 2>::from([1, 2]);
+    assert_eq!(TryRemove::try_remove(&mut v, 0), Ok(1));
+    assert_eq!(
+      TryRemove::try_remove(&mut v, 1),
+      Err(CollectionError::OutOfBounds { index: 1, len: 1 })
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::from_iter([(1, 1)]);
+    assert_eq!(TryRemove::try_remove(&mut m, &1), Ok(1));
+    assert_eq!(
+      TryRemove::try_remove(&mut m, &1),
+      Err(CollectionError::KeyNotFound)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 2]>::from([1, 2]);
+    assert_eq!(TryRemove::try_remove(&mut v, 0), Ok(1));
+    assert_eq!(
+      TryRemove::try_remove(&mut v, 1),
+      Err(CollectionError::OutOfBounds { index: 1, len: 1 })
+    );
+  }
+}
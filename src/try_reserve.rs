@@ -0,0 +1,602 @@
+use crate::{CollectionError, Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to reserve capacity in a collection, reporting failure instead of aborting.
+///
+/// The methods always succeed for collections whose capacity cannot be controlled, such as node-based collections and fixed-size arrays.
+/// Fixed-capacity collections such as `arrayvec::ArrayVec` report `CapacityExceeded` when `additional` values would not fit.
+/// `DashMap` and `DashSet` can only be given capacity upon construction, so they report `CapacityExceeded` in the same way.
+/// `Slab` has no fallible way of reserving, so its implementation is marked `Unsafe`.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use collectivity::{CollectionError, TryReserve};
+///
+/// fn prepare(col: &mut impl TryReserve, n: usize) -> Result<(), CollectionError> {
+///   col.try_reserve(n)
+/// }
+///
+/// assert!(prepare(&mut Vec::<u8>::new(), 10).is_ok());
+/// assert!(matches!(
+///   prepare(&mut Vec::<u8>::new(), usize::MAX),
+///   Err(CollectionError::CapacityExceeded)
+/// ));
+/// # }
+/// ```
+pub trait TryReserve {
+  /// Indicates whether the `try_reserve` and `try_reserve_exact` methods may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// Tries to reserve capacity for at least `additional` more entries.
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError>;
+  /// Tries to reserve capacity for exactly `additional` more entries, where the collection supports it.
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError>;
+}
+
+/// Classifies an error returned by a standard library `try_reserve`, whose kind is not exposed on stable.
+#[cfg(feature = "alloc")]
+fn std_error<V>(len: usize, additional: usize) -> CollectionError {
+  match len
+    .checked_add(additional)
+    .and_then(|n| n.checked_mul(core::mem::size_of::<V>().max(1)))
+  {
+    Some(n) if n <= isize::MAX as usize => CollectionError::AllocationFailed,
+    _ => CollectionError::CapacityExceeded,
+  }
+}
+
+/// Checks whether `additional` more values fit into a collection whose capacity cannot grow on request.
+#[cfg(any(
+  feature = "arrayvec",
+  feature = "dashmap",
+  feature = "heapless",
+  feature = "tinyvec"
+))]
+fn check_fixed_capacity(
+  len: usize,
+  capacity: usize,
+  additional: usize,
+) -> Result<(), CollectionError> {
+  if additional <= capacity - len {
+    Ok(())
+  } else {
+    Err(CollectionError::CapacityExceeded)
+  }
+}
+
+impl<R: TryReserve> TryReserve for &mut R {
+  type Safety = <R as TryReserve>::Safety;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <R as TryReserve>::try_reserve(self, additional)
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <R as TryReserve>::try_reserve_exact(self, additional)
+  }
+}
+
+impl<V, const N: usize> TryReserve for [V; N] {
+  type Safety = Safe;
+  fn try_reserve(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+  fn try_reserve_exact(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryReserve for Vec<V> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<V>(self.len(), additional))
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve_exact(additional)
+      .map_err(|_| std_error::<V>(self.len(), additional))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryReserve for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<V>(self.len(), additional))
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve_exact(additional)
+      .map_err(|_| std_error::<V>(self.len(), additional))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> TryReserve for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+  fn try_reserve_exact(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V: Ord> TryReserve for alloc::collections::BinaryHeap<V> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<V>(self.len(), additional))
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve_exact(additional)
+      .map_err(|_| std_error::<V>(self.len(), additional))
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> TryReserve for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+  fn try_reserve_exact(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord> TryReserve for alloc::collections::BTreeSet<K> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+  fn try_reserve_exact(&mut self, _: usize) -> Result<(), CollectionError> {
+    Ok(())
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryReserve for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<(K, V)>(self.len(), additional))
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, S: BuildHasher> TryReserve for std::collections::HashSet<K, S> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<K>(self.len(), additional))
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, V, S: BuildHasher + Clone> TryReserve for dashmap::DashMap<K, V, S> {
+  type Safety = Safe;
+  /// Succeeds only if the capacity `DashMap` was constructed with already fits `additional` more entries
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), self.capacity(), additional)
+  }
+  /// Succeeds only if the capacity `DashMap` was constructed with already fits `additional` more entries
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), self.capacity(), additional)
+  }
+}
+
+#[cfg(feature = "dashmap")]
+impl<K: Eq + Hash, S: BuildHasher + Clone> TryReserve for dashmap::DashSet<K, S> {
+  type Safety = Safe;
+  /// Succeeds only if the capacity `DashSet` was constructed with already fits `additional` more entries
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), self.capacity(), additional)
+  }
+  /// Succeeds only if the capacity `DashSet` was constructed with already fits `additional` more entries
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), self.capacity(), additional)
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> TryReserve for slab::Slab<V> {
+  /// `Slab` provides no fallible way of growing its storage, so this panics where `reserve` would
+  type Safety = crate::Unsafe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self.reserve(additional);
+    Ok(())
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self.reserve_exact(additional);
+    Ok(())
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl From<smallvec::CollectionAllocErr> for CollectionError {
+  fn from(e: smallvec::CollectionAllocErr) -> Self {
+    match e {
+      smallvec::CollectionAllocErr::CapacityOverflow => CollectionError::CapacityExceeded,
+      smallvec::CollectionAllocErr::AllocErr { .. } => CollectionError::AllocationFailed,
+    }
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> TryReserve for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    Ok(self.try_reserve(additional)?)
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    Ok(self.try_reserve_exact(additional)?)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryReserve for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<(K, V)>(self.len(), additional))
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve_exact(additional)
+      .map_err(|_| std_error::<(K, V)>(self.len(), additional))
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, S: BuildHasher> TryReserve for indexmap::IndexSet<K, S> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve(additional)
+      .map_err(|_| std_error::<K>(self.len(), additional))
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    self
+      .try_reserve_exact(additional)
+      .map_err(|_| std_error::<K>(self.len(), additional))
+  }
+}
+
+/// Classifies an error returned by a `hashbrown` `try_reserve`.
+#[cfg(feature = "hashbrown")]
+fn hashbrown_error(e: hashbrown::TryReserveError) -> CollectionError {
+  match e {
+    hashbrown::TryReserveError::CapacityOverflow => CollectionError::CapacityExceeded,
+    hashbrown::TryReserveError::AllocError { .. } => CollectionError::AllocationFailed,
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> TryReserve for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    hashbrown::HashMap::try_reserve(self, additional).map_err(hashbrown_error)
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, S: BuildHasher> TryReserve for hashbrown::HashSet<K, S> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    hashbrown::HashSet::try_reserve(self, additional).map_err(hashbrown_error)
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> TryReserve for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), arrayvec::ArrayVec::capacity(self), additional)
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryReserve for heapless::Vec<V, N> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), heapless::Vec::capacity(self), additional)
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> TryReserve for heapless::Deque<V, N> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(
+      heapless::Deque::len(self),
+      heapless::Deque::capacity(self),
+      additional,
+    )
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V: Ord, K: heapless::binary_heap::Kind, const N: usize> TryReserve
+  for heapless::BinaryHeap<V, K, N>
+{
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(
+      heapless::BinaryHeap::len(self),
+      heapless::BinaryHeap::capacity(self),
+      additional,
+    )
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> TryReserve
+  for heapless::IndexMap<K, V, S, N>
+{
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(
+      heapless::IndexMap::len(self),
+      heapless::IndexMap::capacity(self),
+      additional,
+    )
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> TryReserve for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    check_fixed_capacity(self.len(), tinyvec::ArrayVec::capacity(self), additional)
+  }
+  /// Same as `try_reserve`
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    <Self as TryReserve>::try_reserve(self, additional)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> TryReserve for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  fn try_reserve(&mut self, additional: usize) -> Result<(), CollectionError> {
+    tinyvec::TinyVec::try_reserve(self, additional)
+      .map_err(|_| std_error::<A::Item>(self.len(), additional))
+  }
+  fn try_reserve_exact(&mut self, additional: usize) -> Result<(), CollectionError> {
+    tinyvec::TinyVec::try_reserve_exact(self, additional)
+      .map_err(|_| std_error::<A::Item>(self.len(), additional))
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn vec() {
+    let mut v = Vec::<i32>::new();
+    assert_eq!(<Vec<i32> as TryReserve>::try_reserve(&mut v, 5), Ok(()));
+    assert!(v.capacity() >= 5);
+    assert_eq!(
+      <Vec<i32> as TryReserve>::try_reserve_exact(&mut v, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+  #[test]
+  fn hash_map() {
+    let mut m = std::collections::HashMap::<i32, i32>::new();
+    assert_eq!(
+      <std::collections::HashMap<i32, i32> as TryReserve>::try_reserve(&mut m, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+  #[test]
+  fn b_tree_set() {
+    assert_eq!(
+      <alloc::collections::BTreeSet<i32> as TryReserve>::try_reserve(&mut Default::default(), 5),
+      Ok(())
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "dashmap")]
+mod dashmap_tests {
+  use super::*;
+  #[test]
+  fn dashmap() {
+    let mut m = dashmap::DashMap::<i32, i32>::with_capacity(16);
+    assert_eq!(TryReserve::try_reserve(&mut m, 4), Ok(()));
+    assert_eq!(
+      TryReserve::try_reserve(&mut m, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+    let mut s = dashmap::DashSet::<i32>::new();
+    assert_eq!(
+      TryReserve::try_reserve_exact(&mut s, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::<i32>::new();
+    assert_eq!(TryReserve::try_reserve(&mut s, 5), Ok(()));
+    assert!(s.capacity() >= 5);
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "smallvec")]
+mod smallvec_tests {
+  use super::*;
+  #[test]
+  fn smallvec() {
+    let mut sv = smallvec::SmallVec::<[i32; 1]>::new();
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 1]> as TryReserve>::try_reserve(&mut sv, 5),
+      Ok(())
+    );
+    assert!(sv.capacity() >= 5);
+    assert_eq!(
+      <smallvec::SmallVec<[i32; 1]> as TryReserve>::try_reserve(&mut sv, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let mut m = indexmap::IndexMap::<u64, u64>::new();
+    assert_eq!(
+      <indexmap::IndexMap<u64, u64> as TryReserve>::try_reserve_exact(&mut m, 4),
+      Ok(())
+    );
+    assert!(m.capacity() >= 4);
+    let mut s = indexmap::IndexSet::<u64>::new();
+    assert_eq!(
+      <indexmap::IndexSet<u64> as TryReserve>::try_reserve(&mut s, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let mut m = hashbrown::HashMap::<u64, u64>::new();
+    assert_eq!(
+      <hashbrown::HashMap<u64, u64> as TryReserve>::try_reserve(&mut m, 4),
+      Ok(())
+    );
+    assert!(m.capacity() >= 4);
+    assert_eq!(
+      <hashbrown::HashMap<u64, u64> as TryReserve>::try_reserve(&mut m, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+    let mut s = hashbrown::HashSet::<u64>::new();
+    assert_eq!(
+      <hashbrown::HashSet<u64> as TryReserve>::try_reserve_exact(&mut s, 4),
+      Ok(())
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "arrayvec")]
+mod arrayvec_tests {
+  use super::*;
+  #[test]
+  fn arrayvec() {
+    let mut v = arrayvec::ArrayVec::<i32, 4>::from_iter([1]);
+    assert_eq!(TryReserve::try_reserve(&mut v, 3), Ok(()));
+    assert_eq!(
+      TryReserve::try_reserve(&mut v, 4),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "heapless")]
+mod heapless_tests {
+  use super::*;
+  #[test]
+  fn heapless() {
+    let mut m = heapless::FnvIndexMap::<i32, i32, 2>::from_iter([(1, 1)]);
+    assert_eq!(TryReserve::try_reserve_exact(&mut m, 1), Ok(()));
+    assert_eq!(
+      TryReserve::try_reserve_exact(&mut m, 2),
+      Err(CollectionError::CapacityExceeded)
+    );
+    let mut d = heapless::Deque::<i32, 2>::new();
+    assert_eq!(
+      TryReserve::try_reserve(&mut d, usize::MAX),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "tinyvec")]
+mod tinyvec_tests {
+  use super::*;
+  #[test]
+  fn tinyvec() {
+    let mut v = tinyvec::ArrayVec::<[i32; 4]>::from_iter([1]);
+    assert_eq!(TryReserve::try_reserve(&mut v, 3), Ok(()));
+    assert_eq!(
+      TryReserve::try_reserve(&mut v, 4),
+      Err(CollectionError::CapacityExceeded)
+    );
+  }
+  #[test]
+  #[cfg(feature = "alloc")]
+  fn tiny_vec() {
+    let mut t = tinyvec::TinyVec::<[i32; 1]>::new();
+    assert_eq!(TryReserve::try_reserve(&mut t, 4), Ok(()));
+    assert!(t.capacity() >= 4);
+  }
+}
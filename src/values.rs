@@ -0,0 +1,368 @@
+use crate::{Safe, SafetyMarker};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(any(feature = "std", feature = "hashbrown", feature = "heapless"))]
+use core::hash::{BuildHasher, Hash};
+
+/// Provides the ability to iterate over the values of a collection, omitting keys.
+///
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use std::collections::BTreeMap;
+/// use collectivity::Values;
+///
+/// fn total<'a>(col: &'a impl Values<Values<'a> = impl Iterator<Item = &'a i32>>) -> i32 {
+///   col.values().sum()
+/// }
+///
+/// assert_eq!(total(&vec![1, 2]), 3);
+/// assert_eq!(total(&BTreeMap::from([("A", 1), ("B", 2)])), 3);
+/// # }
+/// ```
+pub trait Values {
+  /// Indicates whether the `values` method may panic in a particular implementation.
+  type Safety: SafetyMarker;
+  /// The type of the iterator returned by `values`.
+  type Values<'a>: Iterator
+  where
+    Self: 'a;
+  /// Returns an iterator over the values of the collection.
+  fn values<'a>(&'a self) -> Self::Values<'a>;
+}
+
+impl<V> Values for [V] {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+impl<V, const N: usize> Values for [V; N] {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Values for Vec<V> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Values for alloc::collections::VecDeque<V> {
+  type Safety = Safe;
+  type Values<'a>
+    = alloc::collections::vec_deque::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    alloc::collections::VecDeque::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<V> Values for alloc::collections::LinkedList<V> {
+  type Safety = Safe;
+  type Values<'a>
+    = alloc::collections::linked_list::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    alloc::collections::LinkedList::iter(self)
+  }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> Values for alloc::collections::BTreeMap<K, V> {
+  type Safety = Safe;
+  type Values<'a>
+    = alloc::collections::btree_map::Values<'a, K, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    alloc::collections::BTreeMap::values(self)
+  }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> Values for std::collections::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Values<'a>
+    = std::collections::hash_map::Values<'a, K, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    std::collections::HashMap::values(self)
+  }
+}
+
+#[cfg(feature = "serde_json")]
+impl Values for serde_json::Value {
+  type Safety = Safe;
+  type Values<'a>
+    = <Self as crate::Iter>::Iter<'a>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    crate::Iter::iter(self)
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl<'v> Values for simd_json::BorrowedValue<'v> {
+  type Safety = Safe;
+  type Values<'a>
+    = <Self as crate::Iter>::Iter<'a>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    crate::Iter::iter(self)
+  }
+}
+
+#[cfg(feature = "simd-json")]
+impl Values for simd_json::OwnedValue {
+  type Safety = Safe;
+  type Values<'a>
+    = <Self as crate::Iter>::Iter<'a>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    crate::Iter::iter(self)
+  }
+}
+
+#[cfg(feature = "slab")]
+impl<V> Values for slab::Slab<V> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::iter::Map<slab::Iter<'a, V>, fn((usize, &'a V)) -> &'a V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    slab::Slab::iter(self).map(|(_, v)| v)
+  }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A: smallvec::Array> Values for smallvec::SmallVec<A> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[A::Item]>::iter(self)
+  }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: Eq + Hash, V, S: BuildHasher> Values for indexmap::IndexMap<K, V, S> {
+  type Safety = Safe;
+  type Values<'a>
+    = indexmap::map::Values<'a, K, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    indexmap::IndexMap::values(self)
+  }
+}
+
+#[cfg(feature = "hashbrown")]
+impl<K: Eq + Hash, V, S: BuildHasher> Values for hashbrown::HashMap<K, V, S> {
+  type Safety = Safe;
+  type Values<'a>
+    = hashbrown::hash_map::Values<'a, K, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    hashbrown::HashMap::values(self)
+  }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<V, const CAP: usize> Values for arrayvec::ArrayVec<V, CAP> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Values for heapless::Vec<V, N> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[V]>::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<V, const N: usize> Values for heapless::Deque<V, N> {
+  type Safety = Safe;
+  type Values<'a>
+    = <&'a heapless::Deque<V, N> as IntoIterator>::IntoIter
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    heapless::Deque::iter(self)
+  }
+}
+
+#[cfg(feature = "heapless")]
+impl<K: Eq + Hash, V, S: BuildHasher, const N: usize> Values for heapless::IndexMap<K, V, S, N> {
+  type Safety = Safe;
+  type Values<'a>
+    = heapless::IndexMapValues<'a, K, V>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    heapless::IndexMap::values(self)
+  }
+}
+
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> Values for tinyvec::ArrayVec<A> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[A::Item]>::iter(self)
+  }
+}
+
+#[cfg(all(feature = "tinyvec", feature = "alloc"))]
+impl<A: tinyvec::Array> Values for tinyvec::TinyVec<A> {
+  type Safety = Safe;
+  type Values<'a>
+    = core::slice::Iter<'a, A::Item>
+  where
+    Self: 'a;
+
+  fn values<'a>(&'a self) -> Self::Values<'a> {
+    <[A::Item]>::iter(self)
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod tests {
+  use super::*;
+  #[test]
+  fn std() {
+    assert_eq!(
+      <[i32; 2] as Values>::values(&[5, 6]).collect::<Vec<_>>(),
+      vec![&5, &6]
+    );
+    assert_eq!(
+      <alloc::collections::BTreeMap<i32, i32> as Values>::values(&[(1, 2), (0, 1)].into())
+        .collect::<Vec<_>>(),
+      vec![&1, &2]
+    );
+    assert_eq!(
+      <std::collections::HashMap<i32, i32> as Values>::values(&[(0, 1)].into()).collect::<Vec<_>>(),
+      vec![&1]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde_json")]
+mod serde_json_tests {
+  use super::*;
+  #[test]
+  fn serde_json() {
+    assert_eq!(
+      Values::values(&serde_json::json!({ "a": 0 })).collect::<Vec<_>>(),
+      vec![&serde_json::json!(0)]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "slab")]
+mod slab_tests {
+  use super::*;
+  #[test]
+  fn slab() {
+    let mut s = slab::Slab::new();
+    s.insert(5);
+    assert_eq!(
+      <slab::Slab<i32> as Values>::values(&s).collect::<Vec<_>>(),
+      vec![&5]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "indexmap")]
+mod indexmap_tests {
+  use super::*;
+  #[test]
+  fn indexmap() {
+    let m = indexmap::IndexMap::from([(1, 0), (0, 1)]);
+    assert_eq!(
+      <indexmap::IndexMap<i32, i32> as Values>::values(&m).collect::<Vec<_>>(),
+      [&0, &1]
+    );
+  }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hashbrown")]
+mod hashbrown_tests {
+  use super::*;
+  #[test]
+  fn hashbrown() {
+    let m = hashbrown::HashMap::from([(0, 1)]);
+    assert_eq!(
+      <hashbrown::HashMap<i32, i32> as Values>::values(&m).collect::<Vec<_>>(),
+      [&1]
+    );
+  }
+}